@@ -0,0 +1,26 @@
+//! Detects whether the compiler supports the unstable features used by the crate.
+
+use std::env;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(angulus_nightly)");
+
+    // NOTE: only the `step_trait` feature relies on a nightly compiler.
+    if env::var_os("CARGO_FEATURE_STEP_TRAIT").is_some() && is_nightly() {
+        println!("cargo:rustc-cfg=angulus_nightly");
+    }
+}
+
+fn is_nightly() -> bool {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or(false, |version| {
+            version.contains("-nightly") || version.contains("-dev")
+        })
+}
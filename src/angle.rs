@@ -1,10 +1,16 @@
-use core::fmt::Debug;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display};
 use core::iter::Sum;
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 
-use crate::float::Float;
+use crate::float::{Float, FloatCast};
 use crate::macros::{forward_ref_binop, forward_ref_op_assign, forward_ref_unop};
+use crate::units::{DisplayAs, Unit};
 use crate::AngleUnbounded;
+use crate::NonFiniteError;
 
 /// Represents a point on the circle as a unit-agnostic angle.
 ///
@@ -47,6 +53,15 @@ use crate::AngleUnbounded;
 ///
 /// assert!(b.is_nan());
 /// ```
+///
+/// # Hashing
+///
+/// Like the floating point types, [`Angle<f32>`] and [`Angle<f64>`] implement neither
+/// [`Eq`] nor [`Hash`]: `0.0` and `-0.0` radians are equal but have different bits, and a `NaN`
+/// angle is not equal to itself.
+///
+/// To use an angle as a key of a map, use the bits of its [canonical form][Angle::canonicalize]:
+/// angles that are equal have the same canonical bits.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct Angle<F> {
@@ -65,6 +80,12 @@ impl<F: Float> Angle<F> {
     ///
     /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
     pub const EPSILON: Self = Angle::from_radians_unchecked(F::DOUBLE_EPSILON);
+
+    /// The [golden angle](https://en.wikipedia.org/wiki/Golden_angle) (≈ 137.5°),
+    /// i.e. `τ * (1 - 1/φ)` radians where `φ` is the golden ratio.
+    ///
+    /// Rotating by this angle places points evenly around a center, as in a sunflower head.
+    pub const GOLDEN: Self = Angle::from_radians_unchecked(F::GOLDEN_ANGLE);
 }
 
 impl<F: Float> Angle<F> {
@@ -80,6 +101,10 @@ impl<F: Float> Angle<F> {
     pub const RAD_FRAC_PI_6: Self = Angle::from_radians_unchecked(F::FRAC_PI_6);
     /// The angle of π/8 radians.
     pub const RAD_FRAC_PI_8: Self = Angle::from_radians_unchecked(F::FRAC_PI_8);
+    /// The angle of π/12 radians.
+    pub const RAD_FRAC_PI_12: Self = Angle::from_radians_unchecked(F::FRAC_PI_12);
+    /// The angle of π/16 radians.
+    pub const RAD_FRAC_PI_16: Self = Angle::from_radians_unchecked(F::FRAC_PI_16);
 }
 
 impl<F: Float> Angle<F> {
@@ -95,6 +120,10 @@ impl<F: Float> Angle<F> {
     pub const DEG_30: Self = Self::RAD_FRAC_PI_6;
     /// The angle of 22.5°.
     pub const DEG_22_5: Self = Self::RAD_FRAC_PI_8;
+    /// The angle of 15°.
+    pub const DEG_15: Self = Self::RAD_FRAC_PI_12;
+    /// The angle of 11.25°.
+    pub const DEG_11_25: Self = Self::RAD_FRAC_PI_16;
 }
 
 impl<F: Float> Angle<F> {
@@ -198,6 +227,98 @@ impl<F: Float> Angle<F> {
     pub fn from_gradians(gradians: F) -> Self {
         Self::from_radians(gradians * F::GRAD_TO_RAD)
     }
+
+    /// Creates a new angle from a value in arcminutes.
+    #[inline]
+    pub fn from_arcminutes(arcminutes: F) -> Self {
+        Self::from_radians(arcminutes * F::ARCMIN_TO_RAD)
+    }
+
+    /// Creates a new angle from a value in arcseconds.
+    #[inline]
+    pub fn from_arcseconds(arcseconds: F) -> Self {
+        Self::from_radians(arcseconds * F::ARCSEC_TO_RAD)
+    }
+
+    /// Creates a new angle from a value in NATO mils.
+    #[inline]
+    pub fn from_mils(mils: F) -> Self {
+        Self::from_radians(mils * F::MIL_TO_RAD)
+    }
+
+    /// Creates a new angle from a value in hours.
+    #[inline]
+    pub fn from_hours(hours: F) -> Self {
+        Self::from_radians(hours * F::HOUR_TO_RAD)
+    }
+}
+
+impl<F: Float> Angle<F> {
+    /// Creates a new angle from a value in radians, or returns an error if the value is
+    /// infinite or `NaN` instead of creating a `NaN` angle.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFiniteError`] if `radians` is infinite or `NaN`.
+    ///
+    /// ```
+    /// # use angulus::{Angle32, NonFiniteError};
+    /// assert!(Angle32::try_from_radians(1.0).is_ok());
+    /// assert_eq!(Angle32::try_from_radians(f32::INFINITY), Err(NonFiniteError));
+    /// ```
+    #[inline]
+    pub fn try_from_radians(radians: F) -> Result<Self, NonFiniteError> {
+        if radians.is_finite() {
+            Ok(Self::from_radians(radians))
+        } else {
+            Err(NonFiniteError)
+        }
+    }
+
+    /// Creates a new angle from a value in degrees, or returns an error if the value is
+    /// infinite or `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFiniteError`] if `degrees` is infinite or `NaN`.
+    #[inline]
+    pub fn try_from_degrees(degrees: F) -> Result<Self, NonFiniteError> {
+        if degrees.is_finite() {
+            Ok(Self::from_degrees(degrees))
+        } else {
+            Err(NonFiniteError)
+        }
+    }
+
+    /// Creates a new angle from a value in turns, or returns an error if the value is
+    /// infinite or `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFiniteError`] if `turns` is infinite or `NaN`.
+    #[inline]
+    pub fn try_from_turns(turns: F) -> Result<Self, NonFiniteError> {
+        if turns.is_finite() {
+            Ok(Self::from_turns(turns))
+        } else {
+            Err(NonFiniteError)
+        }
+    }
+
+    /// Creates a new angle from a value in gradians, or returns an error if the value is
+    /// infinite or `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFiniteError`] if `gradians` is infinite or `NaN`.
+    #[inline]
+    pub fn try_from_gradians(gradians: F) -> Result<Self, NonFiniteError> {
+        if gradians.is_finite() {
+            Ok(Self::from_gradians(gradians))
+        } else {
+            Err(NonFiniteError)
+        }
+    }
 }
 
 //-------------------------------------------------------------------
@@ -242,6 +363,122 @@ impl<F: Float> Angle<F> {
     pub fn to_gradians(self) -> F {
         self.radians * F::RAD_TO_GRAD
     }
+
+    /// The value of the angle in arcminutes.
+    ///
+    /// This value is in the range `(-10800, 10800]`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_arcminutes(self) -> F {
+        self.radians * F::RAD_TO_ARCMIN
+    }
+
+    /// The value of the angle in arcseconds.
+    ///
+    /// This value is in the range `(-648000, 648000]`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_arcseconds(self) -> F {
+        self.radians * F::RAD_TO_ARCSEC
+    }
+
+    /// The value of the angle in NATO mils.
+    ///
+    /// This value is in the range `(-3200, 3200]`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_mils(self) -> F {
+        self.radians * F::RAD_TO_MIL
+    }
+
+    /// The value of the angle in hours.
+    ///
+    /// This value is in the range `(-12, 12]`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_hours(self) -> F {
+        self.radians * F::RAD_TO_HOUR
+    }
+}
+
+/// Maps a value from the main range of a unit into `[0, full_turn)`.
+#[inline]
+fn positive<F: Float>(value: F, full_turn: F) -> F {
+    if value < F::ZERO {
+        let value = value + full_turn;
+        // NOTE: for tiny negative values, the addition may round up to `full_turn`.
+        if value < full_turn {
+            value
+        } else {
+            F::ZERO
+        }
+    } else {
+        value
+    }
+}
+
+impl<F: Float> Angle<F> {
+    /// The value of the angle in radians.
+    ///
+    /// This value is in the range `[0, 2π)`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_radians_positive(self) -> F {
+        positive(self.radians, F::TAU)
+    }
+
+    /// The value of the angle in degrees.
+    ///
+    /// This value is in the range `[0, 360)`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_degrees_positive(self) -> F {
+        positive(self.to_degrees(), F::from_u64(360))
+    }
+
+    /// The value of the angle in turns.
+    ///
+    /// This value is in the range `[0, 1)`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_turns_positive(self) -> F {
+        positive(self.to_turns(), F::ONE)
+    }
+
+    /// The value of the angle in gradians.
+    ///
+    /// This value is in the range `[0, 400)`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_gradians_positive(self) -> F {
+        positive(self.to_gradians(), F::from_u64(400))
+    }
+
+    /// The value of the angle in radians, in the half-open range `[lower, lower + 2π)`.
+    ///
+    /// This allows to use a branch cut other than [the main range](crate#the-main-range),
+    /// e.g. [`Angle::to_radians_positive`] is the same as `wrap_into(0.0)`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// let a = Angle::from_radians(-3.0 * FRAC_PI_2 / 2.0);
+    /// assert_float_eq!(a.wrap_into(-FRAC_PI_2), 5.0 * FRAC_PI_2 / 2.0, abs <= 0.000001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn wrap_into(self, lower: F) -> F {
+        let offset = (self - Self::from_radians(lower)).to_radians_positive();
+        let value = lower + offset;
+        // NOTE: the addition may round up to the upper bound.
+        if value < lower + F::TAU {
+            value
+        } else {
+            lower
+        }
+    }
 }
 
 impl<F: Float> Angle<F> {
@@ -253,6 +490,225 @@ impl<F: Float> Angle<F> {
     pub fn is_nan(self) -> bool {
         self.radians.is_nan()
     }
+
+    /// Returns `true` if this angle is not NaN.
+    ///
+    /// Since non-finite values produce a `NaN` angle, this is the opposite of [`Angle::is_nan`].
+    #[must_use]
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.radians.is_finite()
+    }
+
+    /// Returns `true` if the value of this angle in [the main range](crate#the-main-range)
+    /// has a positive sign, including `+0.0`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// assert!(Angle::from_degrees(90.0_f32).is_sign_positive());
+    /// assert!(!Angle::from_degrees(270.0_f32).is_sign_positive());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        self.radians.is_sign_positive()
+    }
+
+    /// Returns `true` if the value of this angle in [the main range](crate#the-main-range)
+    /// has a negative sign, including `-0.0`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// assert!(Angle::from_degrees(270.0_f32).is_sign_negative());
+    /// assert!(!Angle::from_degrees(90.0_f32).is_sign_negative());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self.radians.is_sign_negative()
+    }
+
+    /// Returns the canonical form of this angle: `-0.0` radians becomes `0.0` and
+    /// every `NaN` becomes the same `NaN`.
+    ///
+    /// Angles that are equal have canonical forms with the same bits, so the bits of the
+    /// canonical value in radians can be used as a key of a map.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// let a = Angle::from_radians(-0.0_f32).canonicalize();
+    /// let b = Angle::from_radians(0.0_f32).canonicalize();
+    ///
+    /// assert_eq!(a.to_radians().to_bits(), b.to_radians().to_bits());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn canonicalize(self) -> Self {
+        if self.radians.is_nan() {
+            Self::from_radians_unchecked(F::NAN)
+        } else {
+            // NOTE: `-0.0 + 0.0` is `0.0`, any other value is unchanged.
+            Self::from_radians_unchecked(self.radians + F::ZERO)
+        }
+    }
+}
+
+impl<F: Float> Angle<F> {
+    /// Returns an angle with the magnitude of `self` and the sign of `sign`, wrapped into
+    /// [the main range](crate#the-main-range).
+    ///
+    /// Since `-π` wraps to `π`, the half turn is always positive.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(30.0_f32);
+    ///
+    /// assert_float_eq!(a.copysign(-1.0).to_degrees(), -30.0, abs <= 0.0001);
+    /// assert_float_eq!((-a).copysign(2.0).to_degrees(), 30.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn copysign(self, sign: F) -> Self {
+        Self::from_radians(self.radians.copysign(sign))
+    }
+}
+
+//-------------------------------------------------------------------
+// Fixed-point conversion
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Encodes the angle as a fixed-point number of degrees, where `scale` is
+    /// the number of units per degree (e.g. `10_000_000` for `1e-7` degrees per unit).
+    ///
+    /// The value is rounded to the nearest integer.
+    /// If the scaled value does not fit in an [`i64`], it saturates to [`i64::MIN`] or [`i64::MAX`].
+    /// A `NaN` angle is encoded as `0`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// let a = Angle::from_degrees(45.123_456_7_f64);
+    /// assert_eq!(a.to_fixed_point(10_000_000), 451_234_567);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_fixed_point(self, scale: u64) -> i64 {
+        (self.to_degrees() * F::from_u64(scale)).round_to_i64()
+    }
+
+    /// Decodes an angle from a fixed-point number of degrees, where `scale` is
+    /// the number of units per degree.
+    ///
+    /// This is the inverse of [`Angle::to_fixed_point`].
+    #[must_use]
+    #[inline]
+    pub fn from_fixed_point(bits: i64, scale: u64) -> Self {
+        Self::from_degrees(F::from_i64(bits) / F::from_u64(scale))
+    }
+}
+
+//-------------------------------------------------------------------
+// Bearing conversion
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Creates a new angle from a compass bearing in degrees.
+    ///
+    /// A bearing is measured clockwise from North, while angles are measured counterclockwise
+    /// from East (the positive x-axis): a bearing of `0` is the angle of 90° (up) and
+    /// a bearing of `90` is the angle zero (right).
+    ///
+    /// The angle is computed as `90 - bearing` degrees.
+    #[must_use]
+    #[inline]
+    pub fn from_bearing(bearing: F) -> Self {
+        Self::DEG_90 - Self::from_degrees(bearing)
+    }
+
+    /// The value of the angle as a compass bearing in degrees.
+    ///
+    /// This is the inverse of [`Angle::from_bearing`], the bearing is computed as
+    /// `(90 - degrees) mod 360` and is in the range `[0, 360)`.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// assert_float_eq!(Angle32::DEG_90.to_bearing(), 0.0, abs <= 0.0001);
+    /// assert_float_eq!(Angle32::ZERO.to_bearing(), 90.0, abs <= 0.0001);
+    /// assert_float_eq!(Angle32::DEG_180.to_bearing(), 270.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_bearing(self) -> F {
+        (Self::DEG_90 - self).to_degrees_positive()
+    }
+}
+
+//-------------------------------------------------------------------
+// Binary angle conversion
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Encodes the angle as a 16 bits binary angle (BAM), where the full circle
+    /// is linearly mapped onto `0..=u16::MAX`.
+    ///
+    /// The value is rounded to the nearest integer, wrapping around at a full turn.
+    /// A `NaN` angle is encoded as `0`.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// assert_eq!(Angle32::DEG_90.to_bam_u16(), 0x4000);
+    /// assert_eq!(Angle32::DEG_180.to_bam_u16(), 0x8000);
+    /// assert_eq!((-Angle32::DEG_90).to_bam_u16(), 0xC000);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_bam_u16(self) -> u16 {
+        let bits = (self.to_turns_positive() * F::from_u64(1 << 16)).round_to_i64();
+        // NOTE: the truncation wraps `1 << 16` to `0`.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bits = bits as u16;
+        bits
+    }
+
+    /// Decodes an angle from a 16 bits binary angle (BAM).
+    ///
+    /// This is the inverse of [`Angle::to_bam_u16`].
+    #[must_use]
+    #[inline]
+    pub fn from_bam_u16(bits: u16) -> Self {
+        Self::from_turns(F::from_u64(u64::from(bits)) / F::from_u64(1 << 16))
+    }
+
+    /// Encodes the angle as a 32 bits binary angle (BAM), where the full circle
+    /// is linearly mapped onto `0..=u32::MAX`.
+    ///
+    /// The value is rounded to the nearest integer, wrapping around at a full turn.
+    /// A `NaN` angle is encoded as `0`.
+    ///
+    /// ```
+    /// # use angulus::Angle64;
+    /// assert_eq!(Angle64::DEG_180.to_bam_u32(), 0x8000_0000);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_bam_u32(self) -> u32 {
+        let bits = (self.to_turns_positive() * F::from_u64(1 << 32)).round_to_i64();
+        // NOTE: the truncation wraps `1 << 32` to `0`.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bits = bits as u32;
+        bits
+    }
+
+    /// Decodes an angle from a 32 bits binary angle (BAM).
+    ///
+    /// This is the inverse of [`Angle::to_bam_u32`].
+    #[must_use]
+    #[inline]
+    pub fn from_bam_u32(bits: u32) -> Self {
+        Self::from_turns(F::from_u64(u64::from(bits)) / F::from_u64(1 << 32))
+    }
 }
 
 //-------------------------------------------------------------------
@@ -284,6 +740,11 @@ impl Angle<f32> {
     #[must_use = "this returns the result of the operation, without modifying the original"]
     #[inline]
     pub fn to_f64(self) -> Angle<f64> {
+        // Notes: `f32::PI` is slightly greater than `f64::PI`, but both are the upper bound
+        // of the main range.
+        if self == Self::RAD_PI {
+            return Angle::RAD_PI;
+        }
         let radians = f64::from(self.radians);
         debug_assert!(
             radians.is_nan()
@@ -319,11 +780,61 @@ impl From<Angle<f32>> for Angle<f64> {
     }
 }
 
+impl<F: Float> Angle<F> {
+    /// Converts the floating point type to `T`.
+    ///
+    /// This is the generic version of `to_f32` and `to_f64`.
+    ///
+    /// The value is wrapped again into [the main range](crate#the-main-range) because the
+    /// conversion may round it out of it.
+    ///
+    /// ```
+    /// # use angulus::Angle64;
+    /// let a = Angle64::DEG_90;
+    ///
+    /// assert_eq!(a.cast::<f32>(), a.to_f32());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn cast<T: Float>(self) -> Angle<T>
+    where
+        F: FloatCast<T>,
+    {
+        // The upper bound of the main range may be rounded differently by each type.
+        if self == Self::RAD_PI {
+            return Angle::RAD_PI;
+        }
+        Angle::from_radians(self.radians.cast())
+    }
+}
+
+//-------------------------------------------------------------------
+// Display
+//-------------------------------------------------------------------
+
+impl<F: Float + Display> Angle<F> {
+    /// Returns an object that displays the angle in `unit`, like the wrappers of
+    /// [the `units` module](crate::units).
+    ///
+    /// ```
+    /// # use angulus::{Angle32, units::Unit};
+    /// let angle = Angle32::DEG_90;
+    ///
+    /// assert_eq!(format!("{}", angle.display_as(Unit::Degrees)), "90°");
+    /// assert_eq!(format!("{:.1}", angle.display_as(Unit::Gradians)), "100.0g");
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn display_as(self, unit: Unit) -> impl Display {
+        DisplayAs { angle: self, unit }
+    }
+}
+
 //-------------------------------------------------------------------
 // Maths
 //-------------------------------------------------------------------
 
-#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
 impl<F: crate::float::FloatMath> Angle<F> {
     /// Computes the sine.
     #[must_use = "this returns the result of the operation, without modifying the original"]
@@ -346,187 +857,2930 @@ impl<F: crate::float::FloatMath> Angle<F> {
         self.radians.tan()
     }
 
-    /// Simultaneously computes the sine and cosine. Returns `(sin(x), cos(x))`.
+    /// Computes the secant (`1 / cos`).
+    ///
+    /// Returns an infinite value where the cosine is zero.
     #[must_use = "this returns the result of the operation, without modifying the original"]
     #[inline]
-    pub fn sin_cos(self) -> (F, F) {
-        self.radians.sin_cos()
+    pub fn sec(self) -> F {
+        F::ONE / self.radians.cos()
     }
-}
-
-//-------------------------------------------------------------------
-// Ops
-//-------------------------------------------------------------------
-
-impl<F: Float> Add for Angle<F> {
-    type Output = Self;
 
+    /// Computes the cosecant (`1 / sin`).
+    ///
+    /// Returns an infinite value where the sine is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::from_radians(self.radians + rhs.radians)
+    pub fn csc(self) -> F {
+        F::ONE / self.radians.sin()
     }
-}
 
-forward_ref_binop!(impl<F: Float> Add, add for Angle<F>, Angle<F>);
+    /// Computes the cotangent (`cos / sin`).
+    ///
+    /// Returns an infinite value where the sine is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn cot(self) -> F {
+        let (sin, cos) = self.radians.sin_cos();
+        cos / sin
+    }
 
-impl<F: Float> AddAssign for Angle<F> {
+    /// Computes the versine (`1 - cos`).
+    ///
+    /// It is computed as `2 sin²(θ/2)` to avoid the cancellation of `1 - cos` near zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
     #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
+    pub fn versine(self) -> F {
+        let two = F::ONE + F::ONE;
+        let sin = (self.radians / two).sin();
+        two * sin * sin
     }
-}
 
-forward_ref_op_assign!(impl<F: Float> AddAssign, add_assign for Angle<F>, Angle<F>);
+    /// Computes the coversine (`1 - sin`).
+    ///
+    /// It is computed as `2 sin²(π/4 - θ/2)` to avoid the cancellation of `1 - sin` near π/2.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn coversine(self) -> F {
+        let two = F::ONE + F::ONE;
+        let sin = (F::FRAC_PI_4 - self.radians / two).sin();
+        two * sin * sin
+    }
+
+    /// Computes the haversine (`(1 - cos) / 2`).
+    ///
+    /// It is computed as `sin²(θ/2)` to avoid the cancellation of `1 - cos` near zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn haversine(self) -> F {
+        let sin = (self.radians / (F::ONE + F::ONE)).sin();
+        sin * sin
+    }
+
+    /// Computes the length of the chord subtending this angle on a circle of the given radius.
+    ///
+    /// The chord length is `2 * radius * sin(|θ| / 2)`, it uses the unsigned magnitude of the
+    /// angle so it is never negative for a positive radius.
+    /// See also [`Angle::arc_length`].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn chord_length(self, radius: F) -> F {
+        let two = F::ONE + F::ONE;
+        two * radius * (self.magnitude() / two).sin()
+    }
+
+    /// Computes the area of the circular segment, between the arc and the chord, subtending
+    /// this angle on a circle of the given radius.
+    ///
+    /// The segment area is `0.5 * radius² * (|θ| - sin|θ|)`, i.e. the [sector area](Angle::sector_area)
+    /// minus the area of the triangle formed by the chord and the center.
+    /// Like [`Angle::sector_area`], it uses the unsigned magnitude of the angle.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn segment_area(self, radius: F) -> F {
+        let magnitude = self.magnitude();
+        (magnitude - magnitude.sin()) * radius * radius / (F::ONE + F::ONE)
+    }
+
+    /// Simultaneously computes the sine and cosine. Returns `(sin(x), cos(x))`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn sin_cos(self) -> (F, F) {
+        self.radians.sin_cos()
+    }
+
+    /// Computes the unit vector pointing in the direction of the angle.
+    ///
+    /// Returns `(x, y)` where `x = cos(angle)` and `y = sin(angle)`, i.e.
+    /// the angle `0` points toward the positive x-axis and angles increase counterclockwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_vector(self) -> (F, F) {
+        let (sin, cos) = self.radians.sin_cos();
+        (cos, sin)
+    }
+
+    /// Computes the 2×2 matrix of the counterclockwise rotation by this angle.
+    ///
+    /// The matrix is in row-major order, i.e. `matrix[row][column]`:
+    ///
+    /// ```text
+    /// [[cos, -sin],
+    ///  [sin,  cos]]
+    /// ```
+    ///
+    /// and is meant to be multiplied with column vectors.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_rotation_matrix(self) -> [[F; 2]; 2] {
+        let (sin, cos) = self.radians.sin_cos();
+        [[cos, -sin], [sin, cos]]
+    }
+
+    /// Rotates the vector `(x, y)` counterclockwise by this angle.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn rotate_vector(self, (x, y): (F, F)) -> (F, F) {
+        let (sin, cos) = self.radians.sin_cos();
+        (x * cos - y * sin, x * sin + y * cos)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: crate::float::FloatMath> Angle<F> {
+    /// Creates a new angle from the four quadrant arctangent of `y` and `x`.
+    ///
+    /// This is the angle of the vector `(x, y)`. If both `x` and `y` are zero,
+    /// the angle is zero, whatever their signs.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_atan2(1.0_f32, 1.0);
+    /// assert_float_eq!(a.to_degrees(), 45.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_atan2(y: F, x: F) -> Self {
+        // NOTE: `atan2` returns `±π` for `(±0.0, -0.0)`.
+        if x == F::ZERO && y == F::ZERO {
+            return Self::ZERO;
+        }
+
+        // NOTE: `atan2` returns a value in `[-π, π]`.
+        Self::from_radians_partially_unchecked(y.atan2(x))
+    }
+
+    /// Creates a new angle from the arcsine of `x`.
+    ///
+    /// The angle is in the range `[-π/2, π/2]`, or `NaN` if `x` is outside `[-1, 1]`.
+    #[must_use]
+    #[inline]
+    pub fn asin(x: F) -> Self {
+        Self::from_radians_unchecked(x.asin())
+    }
+
+    /// Creates a new angle from the arccosine of `x`.
+    ///
+    /// The angle is in the range `[0, π]`, or `NaN` if `x` is outside `[-1, 1]`.
+    #[must_use]
+    #[inline]
+    pub fn acos(x: F) -> Self {
+        Self::from_radians_unchecked(x.acos())
+    }
+
+    /// Creates a new angle from the arctangent of `x`.
+    ///
+    /// The angle is in the range `[-π/2, π/2]`.
+    #[must_use]
+    #[inline]
+    pub fn atan(x: F) -> Self {
+        Self::from_radians_unchecked(x.atan())
+    }
+}
+
+//-------------------------------------------------------------------
+// Slope
+//-------------------------------------------------------------------
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: crate::float::FloatMath> Angle<F> {
+    /// Creates a new angle from an incline expressed as a percent grade,
+    /// i.e. `atan(percent / 100)`.
+    ///
+    /// The angle is in the range `[-π/2, π/2]`: an infinite grade is a vertical incline.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_percent_grade(100.0_f32);
+    /// assert_float_eq!(a.to_degrees(), 45.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_percent_grade(percent: F) -> Self {
+        Self::atan(percent / F::from_u64(100))
+    }
+
+    /// The incline of the angle expressed as a percent grade, i.e. `tan(self) * 100`.
+    ///
+    /// The grade grows without bound as the angle approaches 90°: an angle of
+    /// exactly [`DEG_90`][Self::DEG_90] gives a huge finite value, whose sign depends on
+    /// how π/2 rounds in `F`. Angles past 90° wrap back to negative grades, like the tangent.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_percent_grade(self) -> F {
+        self.tan() * F::from_u64(100)
+    }
+
+    /// Creates a new angle from a slope `m`, i.e. the rise over the run: `atan(m)`.
+    ///
+    /// The angle is in the range `[-π/2, π/2]`: an infinite slope is a vertical line.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_slope(1.0_f32);
+    /// assert_float_eq!(a.to_degrees(), 45.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_slope(m: F) -> Self {
+        Self::atan(m)
+    }
+
+    /// Creates a new angle from the `rise` and the `run` of a slope, i.e. `atan2(rise, run)`.
+    ///
+    /// Unlike [`from_slope`][Self::from_slope], the sign of both components is kept,
+    /// so the angle covers the whole circle, and a vertical slope (`run = 0`) gives ±90°.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_slope_components(1.0_f32, 0.0);
+    /// assert_float_eq!(a.to_degrees(), 90.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_slope_components(rise: F, run: F) -> Self {
+        Self::from_atan2(rise, run)
+    }
+
+    /// The slope of the angle, i.e. the rise over the run: `tan(self)`.
+    ///
+    /// This is an alias for [`tan`][Self::tan].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_slope(self) -> F {
+        self.tan()
+    }
+}
+
+//-------------------------------------------------------------------
+// Directions
+//-------------------------------------------------------------------
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: crate::float::FloatMath> Angle<F> {
+    /// Returns the bisector of the smaller angle between the directions `a` and `b`.
+    ///
+    /// The bisector is the direction of the sum of the unit vectors of `a` and `b`,
+    /// i.e. `atan2(sin a + sin b, cos a + cos b)`.
+    ///
+    /// # Antipodal directions
+    ///
+    /// When `a` and `b` are opposite, the vector sum is zero (up to rounding errors) and the
+    /// bisector is ambiguous: the direction a quarter turn counterclockwise from `a` is
+    /// returned (i.e. `a + π/2`).
+    ///
+    /// When they are nearly opposite, the vector sum is too short to have an accurate direction,
+    /// so the bisector is computed by [`midpoint`][Self::midpoint] instead.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(170.0_f32);
+    /// let b = Angle::from_degrees(-150.0_f32);
+    ///
+    /// assert_float_eq!(Angle::bisector_of(a, b).to_degrees(), -170.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn bisector_of(a: Self, b: Self) -> Self {
+        let (sin_a, cos_a) = a.sin_cos();
+        let (sin_b, cos_b) = b.sin_cos();
+        let (y, x) = (sin_a + sin_b, cos_a + cos_b);
+        let length_squared = x * x + y * y;
+
+        // NOTE: the rounding errors leave a sum of a few `EPSILON` for opposite directions.
+        let opposite = F::EPSILON * F::from_u64(16);
+        if length_squared <= opposite * opposite {
+            return a + Self::RAD_FRAC_PI_2;
+        }
+
+        // NOTE: below `sqrt(EPSILON)`, the direction of the sum is less accurate than the midpoint.
+        if length_squared <= F::EPSILON {
+            return a.midpoint(b);
+        }
+
+        Self::from_atan2(y, x)
+    }
+
+    /// The dot product of the unit vectors of `self` and `other`, i.e. `cos(self - other)`.
+    ///
+    /// It is `1` for the same direction, `0` for perpendicular directions and `-1`
+    /// for opposite directions.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(30.0_f32);
+    /// let b = Angle::from_degrees(90.0_f32);
+    ///
+    /// assert_float_eq!(a.dot(b), 0.5, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn dot(self, other: Self) -> F {
+        (self - other).cos()
+    }
+
+    /// The perpendicular dot product (2D cross product) of the unit vectors of `self`
+    /// and `other`, i.e. `sin(other - self)`.
+    ///
+    /// It is positive when `other` is counterclockwise from `self`, negative when it is
+    /// clockwise, and zero when the directions are parallel or opposite.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(30.0_f32);
+    /// let b = Angle::from_degrees(60.0_f32);
+    ///
+    /// assert_float_eq!(a.perp_dot(b), 0.5, abs <= 0.0001);
+    /// assert_float_eq!(b.perp_dot(a), -0.5, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn perp_dot(self, other: Self) -> F {
+        (other - self).sin()
+    }
+}
+
+//-------------------------------------------------------------------
+// Fast approximations
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Computes a fast approximation of the sine.
+    ///
+    /// It uses [Bhaskara I's approximation](https://en.wikipedia.org/wiki/Bhaskara_I%27s_sine_approximation_formula),
+    /// which does not require the `std`, `libm` or `cordic` feature.
+    /// The maximum absolute error is `0.0017`, and the approximation is exact at the multiples of 90°.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// assert_float_eq!(Angle32::DEG_30.sin_fast(), 0.5, abs <= 0.0017);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn sin_fast(self) -> F {
+        // NOTE: the formula is valid on `[0, π]`, and the sine is odd.
+        let (x, sign) = if self.radians < F::ZERO {
+            (-self.radians, -F::ONE)
+        } else {
+            (self.radians, F::ONE)
+        };
+
+        let p = x * (F::PI - x);
+        let numerator = F::from_u64(16) * p;
+        let denominator = F::from_u64(5) * F::PI * F::PI - F::from_u64(4) * p;
+        sign * numerator / denominator
+    }
+
+    /// Computes a fast approximation of the cosine.
+    ///
+    /// See [`sin_fast`][Self::sin_fast] for the method and the accuracy.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// assert_float_eq!(Angle32::DEG_60.cos_fast(), 0.5, abs <= 0.0017);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn cos_fast(self) -> F {
+        (self + Self::DEG_90).sin_fast()
+    }
+}
+
+//-------------------------------------------------------------------
+// Related angles
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Returns the opposite angle on the circle, i.e. `self + π`.
+    ///
+    /// Not to be confused with the negation `-self`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(30.0_f32);
+    ///
+    /// assert_float_eq!(a.opposite().to_degrees(), -150.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn opposite(self) -> Self {
+        self + Self::RAD_PI
+    }
+
+    /// Returns the complementary angle, i.e. `π/2 - self`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(30.0_f32);
+    ///
+    /// assert_float_eq!(a.complement().to_degrees(), 60.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn complement(self) -> Self {
+        Self::RAD_FRAC_PI_2 - self
+    }
+
+    /// Returns the supplementary angle, i.e. `π - self`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(30.0_f32);
+    ///
+    /// assert_float_eq!(a.supplement().to_degrees(), 150.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn supplement(self) -> Self {
+        Self::RAD_PI - self
+    }
+
+    /// Returns the angle mirrored across the line at the angle `axis`, i.e. `2 * axis - self`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(30.0_f32);
+    /// let axis = Angle::from_degrees(90.0_f32);
+    ///
+    /// assert_float_eq!(a.reflect(axis).to_degrees(), 150.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn reflect(self, axis: Self) -> Self {
+        axis + (axis - self)
+    }
+
+    /// Returns twice the angle, i.e. `self + self`, wrapped into [the main range](crate#the-main-range).
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(120.0_f32);
+    ///
+    /// assert_float_eq!(a.double().to_degrees(), -120.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn double(self) -> Self {
+        self + self
+    }
+
+    /// Returns half the angle.
+    ///
+    /// Halving a point of the circle is ambiguous: both `θ/2` and `θ/2 + π` double to `θ`.
+    /// This returns the half of the value in [the main range](crate#the-main-range),
+    /// so the result is in `(-π/2, π/2]`. For instance, `350°` is `-10°` and halves to `-5°`,
+    /// not `175°`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(350.0_f32);
+    ///
+    /// assert_float_eq!(a.half().to_degrees(), -5.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn half(self) -> Self {
+        Self::from_radians_unchecked(self.radians / (F::ONE + F::ONE))
+    }
+}
+
+//-------------------------------------------------------------------
+// Distance
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Returns the signed shortest rotation from `self` to `target`.
+    ///
+    /// The result is in [the main range](crate#the-main-range): positive values are
+    /// counterclockwise rotations and negative values are clockwise rotations.
+    /// If `self` and `target` are opposite, the rotation is `π`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(170.0_f32);
+    /// let b = Angle::from_degrees(-170.0_f32);
+    ///
+    /// assert_float_eq!(a.angle_to(b).to_degrees(), 20.0, abs <= 0.0001);
+    /// assert_float_eq!(b.angle_to(a).to_degrees(), -20.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn angle_to(self, target: Self) -> Self {
+        target - self
+    }
+
+    /// Returns the signed shortest rotation from `self` to `other` in radians.
+    ///
+    /// The result is in the range `(-π, π]`: this is the value in radians of [`Angle::angle_to`].
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(170.0_f32);
+    /// let b = Angle::from_degrees(-170.0_f32);
+    ///
+    /// assert_float_eq!(a.delta_radians(b), 20.0_f32.to_radians(), abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn delta_radians(self, other: Self) -> F {
+        self.angle_to(other).to_radians()
+    }
+
+    /// Returns the signed shortest rotation from `self` to `other` in degrees.
+    ///
+    /// The result is in the range `(-180, 180]`: this is the value in degrees of [`Angle::angle_to`].
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(170.0_f32);
+    /// let b = Angle::from_degrees(-170.0_f32);
+    ///
+    /// assert_float_eq!(a.delta_degrees(b), 20.0, abs <= 0.0001);
+    /// assert_float_eq!(b.delta_degrees(a), -20.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn delta_degrees(self, other: Self) -> F {
+        self.angle_to(other).to_degrees()
+    }
+
+    /// Computes the length of the arc subtending this angle on a circle of the given radius.
+    ///
+    /// The arc length is `radius * θ` where `θ` is the signed value in radians in
+    /// [the main range](crate#the-main-range), so it is negative for clockwise angles.
+    /// See also [`Angle::chord_length`].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn arc_length(self, radius: F) -> F {
+        radius * self.radians
+    }
+
+    /// Computes the area of the circular sector subtending this angle on a circle of the given radius.
+    ///
+    /// The sector area is `0.5 * |θ| * radius²`, it uses the unsigned magnitude of the
+    /// angle in [the main range](crate#the-main-range), so clockwise and counterclockwise
+    /// angles have the same area, which is at most half the disc.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// use core::f32::consts::FRAC_PI_4;
+    ///
+    /// assert_float_eq!(Angle32::DEG_90.sector_area(1.0), FRAC_PI_4, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn sector_area(self, radius: F) -> F {
+        self.magnitude() * radius * radius / (F::ONE + F::ONE)
+    }
+
+    /// Returns the length of the shortest arc between `self` and `other`.
+    ///
+    /// The result is in the range `[0, π]`.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(170.0_f32);
+    /// let b = Angle::from_degrees(-170.0_f32);
+    ///
+    /// assert_float_eq!(a.angular_distance(b).to_degrees(), 20.0, abs <= 0.0001);
+    /// assert_float_eq!(b.angular_distance(a).to_degrees(), 20.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn angular_distance(self, other: Self) -> Self {
+        let delta = self.angle_to(other);
+        if delta.radians < F::ZERO {
+            -delta
+        } else {
+            delta
+        }
+    }
+
+    /// Returns `true` if the shortest arc between `self` and `other` is not longer than `tolerance`.
+    ///
+    /// The comparison goes through the seam of [the main range](crate#the-main-range),
+    /// so angles close to `π` and `-π` are approximately equal.
+    /// The sign of `tolerance` is ignored.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// let a = Angle::from_degrees(179.9_f32);
+    /// let b = Angle::from_degrees(-179.9_f32);
+    ///
+    /// assert!(a.approx_eq(b, Angle::from_degrees(0.5)));
+    /// assert!(!a.approx_eq(b, Angle::from_degrees(0.1)));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn approx_eq(self, other: Self, tolerance: Self) -> bool {
+        self.angular_distance(other).radians <= tolerance.angular_distance(Self::ZERO).radians
+    }
+
+    /// Returns `true` if `self` and `other` point in the same direction, within `tolerance`.
+    ///
+    /// This is an alias for [`approx_eq`][Self::approx_eq]. The sign of `tolerance` is ignored.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// let a = Angle::from_degrees(10.0_f32);
+    /// let b = Angle::from_degrees(10.5_f32);
+    ///
+    /// assert!(a.is_parallel(b, Angle::from_degrees(1.0)));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_parallel(self, other: Self, tolerance: Self) -> bool {
+        self.approx_eq(other, tolerance)
+    }
+
+    /// Returns `true` if `self` and `other` point in opposite directions, within `tolerance`.
+    ///
+    /// The sign of `tolerance` is ignored.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// let a = Angle::from_degrees(10.0_f32);
+    /// let b = Angle::from_degrees(-170.5_f32);
+    ///
+    /// assert!(a.is_antiparallel(b, Angle::from_degrees(1.0)));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_antiparallel(self, other: Self, tolerance: Self) -> bool {
+        self.approx_eq(other.opposite(), tolerance)
+    }
+
+    /// Returns `true` if `self` and `other` are a quarter turn apart, in either direction,
+    /// within `tolerance`.
+    ///
+    /// The sign of `tolerance` is ignored.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// let a = Angle::from_degrees(10.0_f32);
+    ///
+    /// assert!(a.is_perpendicular(Angle::from_degrees(100.5), Angle::from_degrees(1.0)));
+    /// assert!(a.is_perpendicular(Angle::from_degrees(-80.5), Angle::from_degrees(1.0)));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_perpendicular(self, other: Self, tolerance: Self) -> bool {
+        let delta = self.angular_distance(other);
+        delta.approx_eq(Self::RAD_FRAC_PI_2, tolerance)
+    }
+}
+
+//-------------------------------------------------------------------
+// Arc
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Returns `true` if the angle is inside the arc going counterclockwise from `start` to `end`.
+    ///
+    /// Both bounds are included. If `start == end`, the arc is a single point and only
+    /// `start` is inside it.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// let start = Angle::from_degrees(170.0_f32);
+    /// let end = Angle::from_degrees(-170.0_f32);
+    ///
+    /// assert!(Angle::DEG_180.is_within_arc(start, end));
+    /// assert!(!Angle::ZERO.is_within_arc(start, end));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_within_arc(self, start: Self, end: Self) -> bool {
+        (self - start).to_radians_positive() <= (end - start).to_radians_positive()
+    }
+
+    /// Clamps the angle into the arc going counterclockwise from `min` to `max`.
+    ///
+    /// If the angle is inside the arc, it is returned unchanged. Otherwise, the
+    /// angularly closest bound is returned (`min` if both bounds are equally close).
+    ///
+    /// If `min == max`, the arc is a single point and `min` is returned.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let min = Angle::from_degrees(170.0_f32);
+    /// let max = Angle::from_degrees(-170.0_f32);
+    ///
+    /// assert_float_eq!(Angle::DEG_180.clamp(min, max).to_degrees(), 180.0, abs <= 0.0001);
+    /// assert_float_eq!(Angle::DEG_90.clamp(min, max).to_degrees(), 170.0, abs <= 0.0001);
+    /// assert_float_eq!((-Angle::DEG_90).clamp(min, max).to_degrees(), -170.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        let offset = (self - min).to_radians_positive();
+        let width = (max - min).to_radians_positive();
+
+        if offset <= width {
+            self
+        } else if offset - width < F::TAU - offset {
+            max
+        } else {
+            min
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Float> Angle<F> {
+    /// Returns the smallest arc that contains all the given angles, as its `(start, end)`
+    /// bounds going counterclockwise, or `None` if `angles` is empty.
+    ///
+    /// The arc is the complement of the largest gap between two consecutive angles.
+    /// If several gaps are equally large, the gap from the largest angle to the smallest one
+    /// (across ±180°) is used, then the gap that starts at the smallest angle.
+    ///
+    /// The `NaN` angles are ignored. If all the angles are `NaN`, returns `None`.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// let angles = [175.0, -170.0, 170.0, 180.0].map(Angle32::from_degrees);
+    ///
+    /// let (start, end) = Angle32::smallest_enclosing_arc(&angles).unwrap();
+    ///
+    /// assert_float_eq!(start.to_degrees(), 170.0, abs <= 0.0001);
+    /// assert_float_eq!(end.to_degrees(), -170.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    pub fn smallest_enclosing_arc(angles: &[Self]) -> Option<(Self, Self)> {
+        let mut sorted: Vec<Self> = angles
+            .iter()
+            .copied()
+            .filter(|angle| !angle.is_nan())
+            .collect();
+        sorted.sort_unstable_by(|a, b| {
+            a.radians
+                .partial_cmp(&b.radians)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        let (&first, &last) = (sorted.first()?, sorted.last()?);
+
+        // The gap going through the seam of the main range.
+        let mut arc = (first, last);
+        let mut largest_gap = first.radians + F::TAU - last.radians;
+
+        for pair in sorted.windows(2) {
+            let gap = pair[1].radians - pair[0].radians;
+            // NOTE: on a tie, the gap found first is kept.
+            if gap > largest_gap {
+                largest_gap = gap;
+                arc = (pair[1], pair[0]);
+            }
+        }
+
+        Some(arc)
+    }
+}
+
+//-------------------------------------------------------------------
+// Rounding
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Rounds the angle to the nearest integer multiple of `step`.
+    ///
+    /// The multiples are counted from the angle zero, using the value of the angle in
+    /// [the main range](crate#the-main-range). Half-way cases are rounded away from zero.
+    ///
+    /// If `step` is zero, the angle is returned unchanged.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let step = Angle::from_degrees(15.0_f32);
+    ///
+    /// assert_float_eq!(Angle::from_degrees(22.0).snap_to(step).to_degrees(), 15.0, abs <= 0.0001);
+    /// assert_float_eq!(Angle::from_degrees(23.0).snap_to(step).to_degrees(), 30.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn snap_to(self, step: Self) -> Self {
+        if step == Self::ZERO {
+            return self;
+        }
+        let count = (self.radians / step.radians).round_to_i64();
+        Self::from_radians(F::from_i64(count) * step.radians)
+    }
+}
+
+//-------------------------------------------------------------------
+// Classification
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Returns the quadrant the angle belongs to, from `1` to `4`.
+    ///
+    /// Quadrants are numbered counterclockwise from the positive x-axis. Each quadrant
+    /// includes its starting boundary and excludes its ending one, i.e. the quadrant `1`
+    /// is `[0°, 90°)`, the quadrant `2` is `[90°, 180°)`, etc.
+    ///
+    /// The result for a `NaN` angle is unspecified.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// assert_eq!(Angle32::ZERO.quadrant(), 1);
+    /// assert_eq!(Angle32::DEG_90.quadrant(), 2);
+    /// assert_eq!(Angle32::DEG_180.quadrant(), 3);
+    /// assert_eq!((-Angle32::DEG_90).quadrant(), 4);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn quadrant(self) -> u8 {
+        let r = self.radians;
+        if r >= F::ZERO {
+            if r < F::FRAC_PI_2 {
+                1
+            } else if r < F::PI {
+                2
+            } else {
+                3
+            }
+        } else if r < -F::FRAC_PI_2 {
+            3
+        } else {
+            4
+        }
+    }
+
+    /// Returns the octant the angle belongs to, from `1` to `8`.
+    ///
+    /// Octants are numbered counterclockwise from the positive x-axis. Each octant
+    /// includes its starting boundary and excludes its ending one, i.e. the octant `1`
+    /// is `[0°, 45°)`, the octant `2` is `[45°, 90°)`, etc.
+    ///
+    /// The result for a `NaN` angle is unspecified.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// assert_eq!(Angle32::ZERO.octant(), 1);
+    /// assert_eq!(Angle32::DEG_45.octant(), 2);
+    /// assert_eq!(Angle32::DEG_180.octant(), 5);
+    /// assert_eq!((-Angle32::DEG_45).octant(), 8);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn octant(self) -> u8 {
+        let r = self.radians;
+        let frac_3_pi_4 = F::FRAC_PI_2 + F::FRAC_PI_4;
+        if r >= F::ZERO {
+            if r < F::FRAC_PI_4 {
+                1
+            } else if r < F::FRAC_PI_2 {
+                2
+            } else if r < frac_3_pi_4 {
+                3
+            } else if r < F::PI {
+                4
+            } else {
+                5
+            }
+        } else if r < -frac_3_pi_4 {
+            5
+        } else if r < -F::FRAC_PI_2 {
+            6
+        } else if r < -F::FRAC_PI_4 {
+            7
+        } else {
+            8
+        }
+    }
+}
+
+impl<F: Float> Angle<F> {
+    /// The unsigned magnitude of the angle in radians, in the range `[0, π]`.
+    #[inline]
+    fn magnitude(self) -> F {
+        if self.radians < F::ZERO {
+            -self.radians
+        } else {
+            self.radians
+        }
+    }
+
+    /// Returns `true` if the angle is acute, i.e. its magnitude is in `(0, π/2)`.
+    ///
+    /// The magnitude is the unsigned value of the angle in [the main range](crate#the-main-range),
+    /// so `-30°` is acute. Angles within [`Angle::EPSILON`] of a right angle are not acute.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_acute(self) -> bool {
+        let magnitude = self.magnitude();
+        F::ZERO < magnitude && magnitude < F::FRAC_PI_2 - F::DOUBLE_EPSILON
+    }
+
+    /// Returns `true` if the angle is right, i.e. its magnitude is `π/2`
+    /// within a tolerance of [`Angle::EPSILON`].
+    ///
+    /// The magnitude is the unsigned value of the angle in [the main range](crate#the-main-range),
+    /// so `-90°` is right.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_right(self) -> bool {
+        let magnitude = self.magnitude();
+        F::FRAC_PI_2 - F::DOUBLE_EPSILON <= magnitude
+            && magnitude <= F::FRAC_PI_2 + F::DOUBLE_EPSILON
+    }
+
+    /// Returns `true` if the angle is obtuse, i.e. its magnitude is in `(π/2, π)`.
+    ///
+    /// The magnitude is the unsigned value of the angle in [the main range](crate#the-main-range),
+    /// so `-120°` is obtuse. Angles within [`Angle::EPSILON`] of a right or a straight angle
+    /// are not obtuse.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_obtuse(self) -> bool {
+        let magnitude = self.magnitude();
+        F::FRAC_PI_2 + F::DOUBLE_EPSILON < magnitude && magnitude < F::PI - F::DOUBLE_EPSILON
+    }
+
+    /// Returns `true` if the angle is straight, i.e. its magnitude is `π`
+    /// within a tolerance of [`Angle::EPSILON`].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_straight(self) -> bool {
+        F::PI - F::DOUBLE_EPSILON <= self.magnitude()
+    }
+}
+
+//-------------------------------------------------------------------
+// Interpolation
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Linearly interpolates between `self` and `other` along the shortest arc.
+    ///
+    /// `t = 0` returns `self` and `t = 1` returns `other`, values outside of `[0, 1]`
+    /// extrapolate along the same direction.
+    ///
+    /// If `self` and `other` are opposite, the interpolation goes counterclockwise.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(170.0_f32);
+    /// let b = Angle::from_degrees(-170.0_f32);
+    ///
+    /// assert_float_eq!(a.lerp(b, 0.5).to_degrees(), 180.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn lerp(self, other: Self, t: F) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Spherically interpolates between `self` and `other` along the shortest arc.
+    ///
+    /// The interpolated angle moves at a constant angular speed when `t` varies linearly.
+    /// Because an [`Angle`] already is a point of the unit circle, this is equivalent
+    /// to [`Angle::lerp`] and it is provided for code ported from quaternion or vector
+    /// based interpolation.
+    ///
+    /// # Antipodal angles
+    ///
+    /// When `self` and `other` are opposite, both arcs have the same length.
+    /// The interpolation then deterministically goes counterclockwise, from `self` to `self + π`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn slerp(self, other: Self, t: F) -> Self {
+        self.lerp(other, t)
+    }
+
+    /// Returns the angle halfway along the shortest arc between `self` and `other`.
+    ///
+    /// When `self` and `other` are opposite, the bisector is ambiguous: the angle
+    /// a quarter turn counterclockwise from `self` is returned (i.e. `self + π/2`).
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(10.0_f32);
+    /// let b = Angle::from_degrees(50.0_f32);
+    ///
+    /// assert_float_eq!(a.midpoint(b).to_degrees(), 30.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn midpoint(self, other: Self) -> Self {
+        self + (other - self) / (F::ONE + F::ONE)
+    }
+
+    /// Rotates `self` toward `target` along the shortest arc, by at most `max_delta`.
+    ///
+    /// If `target` is within `max_delta` of `self`, `target` is returned exactly.
+    /// The sign of `max_delta` is ignored.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let current = Angle::from_degrees(170.0_f32);
+    /// let target = Angle::from_degrees(-150.0_f32);
+    /// let step = Angle::from_degrees(15.0_f32);
+    ///
+    /// let current = current.rotate_towards(target, step);
+    /// assert_float_eq!(current.to_degrees(), -175.0, abs <= 0.0001);
+    ///
+    /// let current = current.rotate_towards(target, step);
+    /// let current = current.rotate_towards(target, step);
+    /// assert_eq!(current, target);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn rotate_towards(self, target: Self, max_delta: Self) -> Self {
+        let delta = self.angle_to(target).radians;
+        let max_delta = max_delta.angular_distance(Self::ZERO).radians;
+
+        if delta.abs() <= max_delta {
+            target
+        } else if delta < F::ZERO {
+            self - Self::from_radians_unchecked(max_delta)
+        } else {
+            self + Self::from_radians_unchecked(max_delta)
+        }
+    }
+
+    /// Returns the parameter `t` such that `a.lerp(b, t)` is `value`, i.e. the position of
+    /// `value` along the shortest arc going from `a` to `b`.
+    ///
+    /// `value` is measured by its signed offset from `a`, in `(-π, π]`.
+    /// If `a` and `b` are equal, the result is `NaN` or infinite.
+    ///
+    /// ```
+    /// # use angulus::Angle;
+    /// # use float_eq::assert_float_eq;
+    /// let a = Angle::from_degrees(170.0_f32);
+    /// let b = Angle::from_degrees(-170.0_f32);
+    /// let value = Angle::from_degrees(175.0_f32);
+    ///
+    /// assert_float_eq!(Angle::inverse_lerp(a, b, value), 0.25, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn inverse_lerp(a: Self, b: Self, value: Self) -> F {
+        (value - a).radians / (b - a).radians
+    }
+
+    /// Maps `value` from the arc going from `in_a` to `in_b` onto the arc going from
+    /// `out_a` to `out_b`, both along the shortest arc.
+    ///
+    /// This is [`Angle::inverse_lerp`] followed by [`Angle::lerp`].
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// let value = Angle32::from_degrees(30.0);
+    /// let remapped = Angle32::remap(
+    ///     value,
+    ///     Angle32::ZERO,
+    ///     Angle32::DEG_90,
+    ///     Angle32::DEG_90,
+    ///     Angle32::ZERO,
+    /// );
+    ///
+    /// assert_float_eq!(remapped.to_degrees(), 60.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn remap(value: Self, in_a: Self, in_b: Self, out_a: Self, out_b: Self) -> Self {
+        out_a.lerp(out_b, Self::inverse_lerp(in_a, in_b, value))
+    }
+}
+
+//-------------------------------------------------------------------
+// Subdivision
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Returns an iterator over `n` evenly spaced angles along the arc going
+    /// counterclockwise from `start` to `end`.
+    ///
+    /// - If `n >= 2`, both `start` and `end` are yielded.
+    /// - If `n == 1`, only `start` is yielded.
+    /// - If `n == 0`, nothing is yielded.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// let angles: Vec<_> = Angle32::subdivide(Angle32::ZERO, Angle32::DEG_90, 3).collect();
+    ///
+    /// assert_eq!(angles.len(), 3);
+    /// assert_float_eq!(angles[1].to_degrees(), 45.0, abs <= 0.0001);
+    /// ```
+    #[inline]
+    pub fn subdivide(start: Self, end: Self, n: usize) -> impl Iterator<Item = Self> {
+        let width = (end - start).to_radians_positive();
+        let intervals = F::from_u64(n.saturating_sub(1).max(1) as u64);
+        let step = width / intervals;
+
+        (0..n).map(move |k| start + Self::from_radians(F::from_u64(k as u64) * step))
+    }
+
+    /// Returns an iterator over `n` angles evenly dividing the whole circle, starting at zero.
+    ///
+    /// The `k`-th angle is `k * 2π / n`, in [the main range](crate#the-main-range).
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// let angles: Vec<_> = Angle32::circle_steps(4).map(|a| a.to_degrees()).collect();
+    ///
+    /// assert_float_eq!(angles[1], 90.0, abs <= 0.0001);
+    /// assert_float_eq!(angles[3], -90.0, abs <= 0.0001);
+    /// ```
+    #[inline]
+    pub fn circle_steps(n: usize) -> impl Iterator<Item = Self> {
+        let count = F::from_u64(n as u64);
+        (0..n).map(move |k| Self::from_turns(F::from_u64(k as u64) / count))
+    }
+}
+
+//-------------------------------------------------------------------
+// Regular polygons
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Returns the interior angle of a regular polygon with `n` sides, i.e. `(n - 2)π / n`.
+    ///
+    /// A polygon has at least three sides: if `n < 3`, the angle is `NaN`.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// assert_float_eq!(Angle32::polygon_interior(6).to_degrees(), 120.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn polygon_interior(n: u32) -> Self {
+        if n < 3 {
+            return Self::from_radians_unchecked(F::NAN);
+        }
+        let n = F::from_u64(u64::from(n));
+        Self::from_radians((n - F::from_u64(2)) * F::PI / n)
+    }
+
+    /// Returns the exterior angle of a regular polygon with `n` sides, i.e. `2π / n`.
+    ///
+    /// A polygon has at least three sides: if `n < 3`, the angle is `NaN`.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// assert_float_eq!(Angle32::polygon_exterior(6).to_degrees(), 60.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn polygon_exterior(n: u32) -> Self {
+        if n < 3 {
+            return Self::from_radians_unchecked(F::NAN);
+        }
+        Self::from_radians(F::TAU / F::from_u64(u64::from(n)))
+    }
+}
+
+//-------------------------------------------------------------------
+// Quantization
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Returns the index, in `0..n`, of the bucket containing the angle when the whole
+    /// circle is divided into `n` equal buckets.
+    ///
+    /// The bucket `k` covers the angles from `k * 2π / n` (included) to `(k + 1) * 2π / n`
+    /// (excluded), going counterclockwise from zero. Like [`Angle::to_turns_positive`],
+    /// a tiny negative angle that rounds to a full turn is in the bucket `0`.
+    /// A `NaN` angle is in the bucket `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// assert_eq!(Angle32::from_degrees(10.0).quantize(4), 0);
+    /// assert_eq!(Angle32::from_degrees(100.0).quantize(4), 1);
+    /// assert_eq!(Angle32::from_degrees(-10.0).quantize(4), 3);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn quantize(self, n: usize) -> usize {
+        assert!(n > 0, "the number of buckets must be positive");
+        let index = (self.to_turns_positive() * F::from_u64(n as u64)).trunc_to_i64();
+        // NOTE: `to_turns_positive` is in `[0, 1)`, but the product may round up to `n`.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let index = index as usize;
+        index.min(n - 1)
+    }
+
+    /// Returns the angle at the center of the bucket `index` when the whole circle
+    /// is divided into `n` equal buckets, i.e. `(index + 0.5) * 2π / n`.
+    ///
+    /// This is the inverse of [`Angle::quantize`], up to half a bucket width.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// assert_float_eq!(Angle32::from_bucket(1, 4).to_degrees(), 135.0, abs <= 0.0001);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_bucket(index: usize, n: usize) -> Self {
+        let numerator = F::from_u64(2 * index as u64 + 1);
+        Self::from_turns(numerator / F::from_u64(2 * n as u64))
+    }
+}
+
+//-------------------------------------------------------------------
+// Batch conversion
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Converts a slice of values in degrees into angles, writing them into `dst`.
+    ///
+    /// This is equivalent to calling [`Angle::from_degrees`] on each value, in a single
+    /// tight loop the compiler can vectorize.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// let degrees = [0.0, 90.0, 450.0];
+    /// let mut angles = [Angle32::ZERO; 3];
+    ///
+    /// Angle32::from_degrees_slice(&degrees, &mut angles);
+    ///
+    /// assert_eq!(angles[1], Angle32::from_degrees(90.0));
+    /// ```
+    #[inline]
+    pub fn from_degrees_slice(src: &[F], dst: &mut [Self]) {
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "source and destination slices have different lengths"
+        );
+
+        for (dst, &degrees) in dst.iter_mut().zip(src) {
+            *dst = Self::from_degrees(degrees);
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: crate::float::FloatMath> Angle<F> {
+    /// Computes the sine and the cosine of a slice of angles, writing `(sin, cos)` pairs into `dst`.
+    ///
+    /// This is equivalent to calling [`Angle::sin_cos`] on each angle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// let angles = [Angle32::ZERO, Angle32::DEG_90];
+    /// let mut values = [(0.0, 0.0); 2];
+    ///
+    /// Angle32::sin_cos_slice(&angles, &mut values);
+    ///
+    /// assert_float_eq!(values[1].0, 1.0, abs <= 0.000001);
+    /// assert_float_eq!(values[1].1, 0.0, abs <= 0.000001);
+    /// ```
+    #[inline]
+    pub fn sin_cos_slice(src: &[Self], dst: &mut [(F, F)]) {
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "source and destination slices have different lengths"
+        );
+
+        for (dst, angle) in dst.iter_mut().zip(src) {
+            *dst = angle.sin_cos();
+        }
+    }
+}
+
+//-------------------------------------------------------------------
+// Unwrapping
+//-------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl<F: Float> Angle<F> {
+    /// Unwraps a sequence of angles into a continuous sequence of unbounded angles,
+    /// by removing the jumps of a full turn between consecutive angles.
+    ///
+    /// Each step is the shortest rotation from one angle to the next, so the actual change
+    /// between two consecutive samples must be less than `π` (half a turn).
+    /// The first angle is kept as is.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// let angles = [170.0, 180.0, -170.0, -160.0].map(Angle32::from_degrees);
+    ///
+    /// let unwrapped = Angle32::unwrap_sequence(&angles);
+    ///
+    /// assert_float_eq!(unwrapped[3].to_degrees(), 200.0, abs <= 0.001);
+    /// ```
+    #[must_use]
+    pub fn unwrap_sequence(src: &[Self]) -> Vec<AngleUnbounded<F>> {
+        let mut unwrapped = Vec::with_capacity(src.len());
+
+        if let Some((&first, rest)) = src.split_first() {
+            let mut previous = first;
+            let mut current = first.to_unbounded();
+            unwrapped.push(current);
+
+            for &angle in rest {
+                current += previous.angle_to(angle);
+                previous = angle;
+                unwrapped.push(current);
+            }
+        }
+
+        unwrapped
+    }
+}
+
+//-------------------------------------------------------------------
+// Summation
+//-------------------------------------------------------------------
+
+impl<F: Float> Angle<F> {
+    /// Sums the angles using a compensated (Kahan–Babuška) summation.
+    ///
+    /// The values in radians are accumulated with a running compensation of the rounding
+    /// errors, then the total is wrapped into [the main range](crate#the-main-range).
+    /// This is slower than [`Sum`] but much more accurate for long sequences.
+    ///
+    /// ```
+    /// # use angulus::Angle32;
+    /// # use float_eq::assert_float_eq;
+    /// let angles = core::iter::repeat(Angle32::from_degrees(0.1)).take(3600);
+    ///
+    /// assert_float_eq!(Angle32::kahan_sum(angles).to_degrees(), 0.0, abs <= 0.001);
+    /// ```
+    #[must_use]
+    pub fn kahan_sum<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        let mut sum = F::ZERO;
+        let mut compensation = F::ZERO;
+
+        for angle in iter {
+            let x = angle.radians;
+            let t = sum + x;
+            compensation += if sum.abs() >= x.abs() {
+                (sum - t) + x
+            } else {
+                (x - t) + sum
+            };
+            sum = t;
+        }
+
+        Self::from_radians(sum + compensation)
+    }
+}
+
+//-------------------------------------------------------------------
+// Ops
+//-------------------------------------------------------------------
+
+impl<F: Float> Add for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_radians(self.radians + rhs.radians)
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Add, add for Angle<F>, Angle<F>);
+
+impl<F: Float> AddAssign for Angle<F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> AddAssign, add_assign for Angle<F>, Angle<F>);
 
 impl<F: Float> Sub for Angle<F> {
     type Output = Self;
 
-    #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::from_radians(self.radians - rhs.radians)
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_radians(self.radians - rhs.radians)
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Sub, sub for Angle<F>, Angle<F>);
+
+impl<F: Float> SubAssign for Angle<F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> SubAssign, sub_assign for Angle<F>, Angle<F>);
+
+/// Adds an angle of the other type.
+///
+/// The result has the type of the left operand, so it is wrapped into [the main range](crate#the-main-range).
+impl<F: Float> Add<AngleUnbounded<F>> for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: AngleUnbounded<F>) -> Self::Output {
+        Self::from_radians(self.radians + rhs.to_radians())
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Add, add for Angle<F>, AngleUnbounded<F>);
+
+impl<F: Float> AddAssign<AngleUnbounded<F>> for Angle<F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: AngleUnbounded<F>) {
+        *self = *self + rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> AddAssign, add_assign for Angle<F>, AngleUnbounded<F>);
+
+/// Subtracts an angle of the other type.
+///
+/// The result has the type of the left operand, so it is wrapped into [the main range](crate#the-main-range).
+impl<F: Float> Sub<AngleUnbounded<F>> for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: AngleUnbounded<F>) -> Self::Output {
+        Self::from_radians(self.radians - rhs.to_radians())
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Sub, sub for Angle<F>, AngleUnbounded<F>);
+
+impl<F: Float> SubAssign<AngleUnbounded<F>> for Angle<F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: AngleUnbounded<F>) {
+        *self = *self - rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> SubAssign, sub_assign for Angle<F>, AngleUnbounded<F>);
+
+impl<F: Float> Mul<F> for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: F) -> Self::Output {
+        Self::from_radians(self.radians * rhs)
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for Angle<F>, F);
+
+impl Mul<Angle<f32>> for f32 {
+    type Output = Angle<f32>;
+
+    #[inline]
+    fn mul(self, rhs: Angle<f32>) -> Self::Output {
+        rhs * self
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for f32, Angle<f32>);
+
+impl Mul<Angle<f64>> for f64 {
+    type Output = Angle<f64>;
+
+    #[inline]
+    fn mul(self, rhs: Angle<f64>) -> Self::Output {
+        rhs * self
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for f64, Angle<f64>);
+
+impl<F: Float> MulAssign<F> for Angle<F> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        *self = *self * rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> MulAssign, mul_assign for Angle<F>, F);
+
+impl<F: Float> Mul<i32> for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        self * F::from_i64(i64::from(rhs))
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for Angle<F>, i32);
+
+impl<F: Float> Mul<Angle<F>> for i32 {
+    type Output = Angle<F>;
+
+    #[inline]
+    fn mul(self, rhs: Angle<F>) -> Self::Output {
+        rhs * self
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for i32, Angle<F>);
+
+impl<F: Float> MulAssign<i32> for Angle<F> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> MulAssign, mul_assign for Angle<F>, i32);
+
+impl<F: Float> Mul<u32> for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> Self::Output {
+        self * F::from_u64(u64::from(rhs))
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for Angle<F>, u32);
+
+impl<F: Float> Mul<Angle<F>> for u32 {
+    type Output = Angle<F>;
+
+    #[inline]
+    fn mul(self, rhs: Angle<F>) -> Self::Output {
+        rhs * self
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for u32, Angle<F>);
+
+impl<F: Float> MulAssign<u32> for Angle<F> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32) {
+        *self = *self * rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> MulAssign, mul_assign for Angle<F>, u32);
+
+impl<F: Float> Div<F> for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: F) -> Self::Output {
+        Self::from_radians(self.radians / rhs)
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Div, div for Angle<F>, F);
+
+impl<F: Float> DivAssign<F> for Angle<F> {
+    #[inline]
+    fn div_assign(&mut self, rhs: F) {
+        *self = *self / rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> DivAssign, div_assign for Angle<F>, F);
+
+impl<F: Float> Div for Angle<F> {
+    type Output = F;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.radians / rhs.radians
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Div, div for Angle<F>, Angle<F>);
+
+impl<F: Float> Rem<F> for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: F) -> Self::Output {
+        Self::from_radians(self.radians % rhs)
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Rem, rem for Angle<F>, F);
+
+impl<F: Float> RemAssign<F> for Angle<F> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: F) {
+        *self = *self % rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> RemAssign, rem_assign for Angle<F>, F);
+
+impl<F: Float> Neg for Angle<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        debug_assert!(self.radians.is_nan() || (-F::PI < self.radians && self.radians <= F::PI));
+        if self.radians == F::PI {
+            self
+        } else {
+            Self::from_radians_unchecked(-self.radians)
+        }
+    }
+}
+
+forward_ref_unop!(impl<F: Float> Neg, neg for Angle<F>);
+
+impl<F: Float + Sum> Sum for Angle<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Angle::from_radians(iter.map(|x| x.radians).sum())
+    }
+}
+
+impl<F: Float + Sum> FromIterator<Angle<F>> for Angle<F> {
+    /// Collects the angles into their sum.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Angle<F>>>(iter: I) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use crate::{Angle, Angle32, Angle64, AngleUnbounded32, NonFiniteError};
+
+    #[test]
+    fn angle_cast() {
+        for x in [0.0, 1.0, -2.5, core::f64::consts::PI, 1e10] {
+            let a = Angle64::from_radians(x);
+            assert_eq!(a.cast::<f32>(), a.to_f32());
+            assert_eq!(a.cast::<f64>(), a);
+
+            let a = a.to_f32();
+            assert_eq!(a.cast::<f64>(), a.to_f64());
+            assert_eq!(a.cast::<f32>(), a);
+        }
+
+        // The upper bound of the main range is preserved.
+        assert_eq!(Angle64::DEG_180.cast::<f32>(), Angle32::DEG_180);
+        assert_eq!(Angle32::DEG_180.cast::<f64>(), Angle64::DEG_180);
+        assert_eq!(Angle32::DEG_180.to_f64(), Angle64::DEG_180);
+    }
+
+    #[test]
+    fn angle_pi_eq_neg_pi() {
+        assert_eq!(
+            Angle::from_radians(core::f32::consts::PI),
+            Angle::from_radians(-core::f32::consts::PI),
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn angle_signed_zero_hash() {
+        use std::collections::HashMap;
+
+        let zero = Angle32::from_radians(0.0);
+        let neg_zero = Angle32::from_radians(-0.0);
+        assert_eq!(zero, neg_zero);
+        assert_ne!(zero.to_radians().to_bits(), neg_zero.to_radians().to_bits());
+        assert_eq!(
+            zero.canonicalize().to_radians().to_bits(),
+            neg_zero.canonicalize().to_radians().to_bits()
+        );
+        assert!(neg_zero.canonicalize().is_sign_positive());
+        assert!((-Angle64::ZERO).canonicalize().is_sign_positive());
+        assert_eq!(Angle32::DEG_90.canonicalize(), Angle32::DEG_90);
+        assert!(Angle32::from_radians(f32::NAN).canonicalize().is_nan());
+
+        let mut map = HashMap::new();
+        map.insert(zero.canonicalize().to_radians().to_bits(), "zero");
+        map.insert(
+            neg_zero.canonicalize().to_radians().to_bits(),
+            "negative zero",
+        );
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&Angle32::ZERO.to_radians().to_bits()], "negative zero");
+    }
+
+    #[test]
+    fn angle_try_from() {
+        assert_eq!(
+            Angle32::try_from_radians(1.0),
+            Ok(Angle32::from_radians(1.0))
+        );
+        assert_eq!(
+            Angle32::try_from_degrees(450.0),
+            Ok(Angle32::from_degrees(450.0))
+        );
+        assert_eq!(
+            Angle64::try_from_turns(-0.25),
+            Ok(Angle64::from_turns(-0.25))
+        );
+        assert_eq!(
+            Angle64::try_from_gradians(1e300),
+            Ok(Angle64::from_gradians(1e300))
+        );
+
+        macro_rules! check_err {
+            ($($value:expr),*) => {
+                $(
+                    assert_eq!(Angle32::try_from_radians($value), Err(NonFiniteError));
+                    assert_eq!(Angle32::try_from_degrees($value), Err(NonFiniteError));
+                    assert_eq!(Angle32::try_from_turns($value), Err(NonFiniteError));
+                    assert_eq!(Angle32::try_from_gradians($value), Err(NonFiniteError));
+                )*
+            };
+        }
+
+        check_err!(f32::INFINITY, f32::NEG_INFINITY, f32::NAN);
+    }
+
+    #[test]
+    fn angle_golden() {
+        assert_float_eq!(Angle32::GOLDEN.to_degrees(), 137.5077, abs <= 1e-4);
+        assert_float_eq!(
+            Angle64::GOLDEN.to_degrees(),
+            137.507_764_050_037_85,
+            abs <= 1e-12
+        );
+
+        let phi = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        let expected = core::f64::consts::TAU * (1.0 - 1.0 / phi);
+        assert_float_eq!(Angle64::GOLDEN.to_radians(), expected, ulps <= 1);
+    }
+
+    #[test]
+    fn angle_small_fraction_constants() {
+        assert_float_eq!(Angle32::DEG_15.to_degrees(), 15.0, abs <= 1e-5);
+        assert_float_eq!(Angle32::DEG_11_25.to_degrees(), 11.25, abs <= 1e-5);
+        assert_float_eq!(Angle64::RAD_FRAC_PI_12.to_degrees(), 15.0, abs <= 1e-12);
+        assert_float_eq!(Angle64::RAD_FRAC_PI_16.to_degrees(), 11.25, abs <= 1e-12);
+        assert_float_eq!(
+            (Angle32::DEG_15 * 6.0).to_radians(),
+            Angle32::DEG_90.to_radians(),
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            (Angle32::DEG_11_25 * 2.0).to_radians(),
+            Angle32::DEG_22_5.to_radians(),
+            abs <= 1e-6
+        );
+    }
+
+    #[test]
+    fn angle_add_sub_unbounded_wraps() {
+        let a = Angle32::DEG_90;
+        let b = AngleUnbounded32::from_turns(2.5);
+
+        let sum: Angle32 = a + b;
+        assert_float_eq!(sum.to_degrees(), -90.0, abs <= 1e-3);
+        let diff: Angle32 = a - b;
+        assert_float_eq!(diff.to_degrees(), -90.0, abs <= 1e-3);
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, sum);
+        c -= &b;
+        assert_float_eq!(c.to_degrees(), 90.0, abs <= 1e-3);
+    }
+
+    #[test]
+    fn angle_mul_integer() {
+        let angle: Angle32 = Angle32::SIXTH * 6;
+        assert!(angle.approx_eq(Angle32::ZERO, Angle32::EPSILON));
+        assert!((6_u32 * Angle32::SIXTH).approx_eq(Angle32::ZERO, Angle32::EPSILON));
+        assert_eq!(Angle32::DEG_30 * 3_i32, Angle32::DEG_30 * 3.0);
+        assert_eq!(Angle32::DEG_30 * -3_i32, Angle32::DEG_30 * -3.0);
+        assert_eq!(-3 * Angle32::DEG_30, Angle32::DEG_30 * -3.0);
+
+        let mut angle = Angle64::DEG_15;
+        angle *= 4_u32;
+        assert_eq!(angle, Angle64::DEG_15 * 4.0);
+    }
+
+    #[test]
+    fn angle_div_angle() {
+        assert_float_eq!(Angle32::DEG_90 / Angle32::DEG_30, 3.0, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_30 / Angle32::DEG_90, 1.0 / 3.0, abs <= 1e-6);
+        assert_float_eq!(-Angle32::DEG_90 / Angle32::DEG_30, -3.0, abs <= 1e-6);
+        assert!((Angle32::DEG_90 / Angle32::ZERO).is_infinite());
+    }
+
+    #[test]
+    fn angle_rem() {
+        let period = Angle32::DEG_30.to_radians();
+
+        assert_float_eq!(
+            (Angle32::from_degrees(100.0) % period).to_degrees(),
+            10.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            (Angle32::from_degrees(-100.0) % period).to_degrees(),
+            -10.0,
+            abs <= 1e-4
+        );
+
+        let mut angle = Angle32::from_degrees(100.0);
+        angle %= period;
+        assert_float_eq!(angle.to_degrees(), 10.0, abs <= 1e-4);
+    }
+
+    #[test]
+    fn angle_sum_is_accurate() {
+        const ANGLES: [f32; 20] = [
+            0.711_889,
+            0.612_456_56,
+            -1.165_211_3,
+            -1.452_307,
+            -0.587_785_5,
+            0.593_006_5,
+            0.012_860_533,
+            -1.142_349_6,
+            -1.302_776_9,
+            0.510_909_6,
+            2.365_249_2,
+            1.743_016_8,
+            1.165_635_7,
+            -2.191_822_8,
+            2.505_914_4,
+            0.498_677,
+            2.496_595,
+            -0.108_386_315,
+            0.991_436_9,
+            -2.835_525_5,
+        ];
+
+        let angles = ANGLES.map(Angle32::from_radians);
+
+        let sum: Angle32 = angles.iter().copied().sum();
+        let add = angles.iter().copied().fold(Angle::ZERO, |a, b| a + b);
+
+        assert_float_eq!(sum.to_radians(), add.to_radians(), abs <= 1e-5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn angle_kahan_sum_is_accurate() {
+        let values = (0..10_000_u16).map(|i| 0.001_f32 + 0.000_1 * f32::from(i % 7));
+
+        let reference = values.clone().map(f64::from).sum::<f64>();
+        let reference = Angle64::from_radians(reference).to_radians();
+
+        let kahan = Angle32::kahan_sum(values.clone().map(Angle32::from_radians));
+        let naive: Angle32 = values.map(Angle32::from_radians).sum();
+
+        let kahan_error = (f64::from(kahan.to_radians()) - reference).abs();
+        let naive_error = (f64::from(naive.to_radians()) - reference).abs();
+
+        assert!(kahan_error < 1e-5, "{kahan_error}");
+        assert!(kahan_error < naive_error);
+    }
+
+    #[test]
+    fn angle_collect_is_sum() {
+        let angles = [30.0, 100.0, -45.0, 170.0].map(Angle32::from_degrees);
+
+        let collected: Angle32 = angles.iter().copied().collect();
+        let add = angles.iter().fold(Angle::ZERO, |a, b| a + b);
+
+        assert_float_eq!(collected.to_radians(), add.to_radians(), abs <= 1e-5);
+    }
+
+    #[test]
+    fn angle_lerp_takes_shortest_arc() {
+        let a = Angle32::from_degrees(170.0);
+        let b = Angle32::from_degrees(-170.0);
+
+        assert_float_eq!(a.lerp(b, 0.0).to_degrees(), 170.0, abs <= 1e-4);
+        assert_float_eq!(a.lerp(b, 0.5).to_degrees(), 180.0, abs <= 1e-4);
+        assert_float_eq!(a.lerp(b, 1.0).to_degrees(), -170.0, abs <= 1e-4);
+        assert_float_eq!(b.lerp(a, 0.5).to_degrees(), 180.0, abs <= 1e-4);
+
+        assert_float_eq!(a.lerp(b, 2.0).to_degrees(), -150.0, abs <= 1e-4);
+        assert_float_eq!(a.lerp(b, -1.0).to_degrees(), 150.0, abs <= 1e-4);
+    }
+
+    #[test]
+    fn angle_slerp() {
+        macro_rules! check {
+            ($from:expr, $to:expr, [$q1:expr, $q2:expr, $q3:expr]) => {
+                let a = Angle32::from_degrees($from);
+                let b = Angle32::from_degrees($to);
+                assert_float_eq!(a.slerp(b, 0.25).to_degrees(), $q1, abs <= 1e-4);
+                assert_float_eq!(a.slerp(b, 0.5).to_degrees(), $q2, abs <= 1e-4);
+                assert_float_eq!(a.slerp(b, 0.75).to_degrees(), $q3, abs <= 1e-4);
+            };
+        }
+
+        check!(10.0, 100.0, [32.5, 55.0, 77.5]);
+        check!(100.0, 10.0, [77.5, 55.0, 32.5]);
+        check!(170.0, -170.0, [175.0, 180.0, -175.0]);
+        // Antipodal: goes counterclockwise.
+        check!(0.0, 180.0, [45.0, 90.0, 135.0]);
+        check!(90.0, -90.0, [135.0, 180.0, -135.0]);
+    }
+
+    #[test]
+    fn angle_midpoint() {
+        let a = Angle32::from_degrees(10.0);
+        let b = Angle32::from_degrees(50.0);
+        assert_float_eq!(a.midpoint(b).to_degrees(), 30.0, abs <= 1e-4);
+        assert_float_eq!(b.midpoint(a).to_degrees(), 30.0, abs <= 1e-4);
+
+        let a = Angle32::from_degrees(170.0);
+        let b = Angle32::from_degrees(-170.0);
+        assert_float_eq!(a.midpoint(b).to_degrees(), 180.0, abs <= 1e-4);
+
+        // Antipodal
+        assert_float_eq!(
+            Angle32::ZERO.midpoint(Angle32::DEG_180).to_degrees(),
+            90.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::DEG_180.midpoint(Angle32::ZERO).to_degrees(),
+            -90.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[test]
+    fn angle_related_angles() {
+        assert_float_eq!(
+            Angle32::DEG_30.complement().to_radians(),
+            Angle32::DEG_60.to_radians(),
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            Angle32::DEG_30.supplement().to_radians(),
+            Angle32::from_degrees(150.0).to_radians(),
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            Angle32::DEG_30.opposite().to_radians(),
+            -Angle32::from_degrees(150.0).to_radians(),
+            abs <= 1e-6
+        );
+
+        assert_eq!(Angle32::ZERO.opposite(), Angle32::DEG_180);
+        assert_eq!(Angle32::DEG_180.opposite(), Angle32::ZERO);
+        assert_eq!(Angle32::ZERO.supplement(), Angle32::DEG_180);
+        assert_eq!(Angle32::DEG_90.complement(), Angle32::ZERO);
+
+        // Wrapped into the main range.
+        assert_float_eq!(
+            Angle32::DEG_180.complement().to_degrees(),
+            -90.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            (-Angle32::DEG_90).supplement().to_degrees(),
+            -90.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[test]
+    fn angle_reflect() {
+        for x in [0.0, 30.0, -60.0, 135.0, 180.0] {
+            let a = Angle32::from_degrees(x);
+            assert_eq!(a.reflect(Angle32::ZERO), -a);
+        }
+
+        // Reflecting across 45° swaps 0° and 90°.
+        assert_float_eq!(
+            Angle32::ZERO.reflect(Angle32::DEG_45).to_degrees(),
+            90.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::DEG_90.reflect(Angle32::DEG_45).to_degrees(),
+            0.0,
+            abs <= 1e-4
+        );
+
+        // Wrapped into the main range.
+        assert_float_eq!(
+            Angle32::from_degrees(-170.0)
+                .reflect(Angle32::from_degrees(170.0))
+                .to_degrees(),
+            150.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[test]
+    fn angle_half_double() {
+        assert_eq!(Angle32::DEG_45.double(), Angle32::DEG_90);
+        assert_eq!(Angle32::DEG_90.double(), Angle32::DEG_180);
+        assert_float_eq!(
+            Angle32::from_degrees(120.0).double().to_degrees(),
+            -120.0,
+            abs <= 1e-4
+        );
+
+        assert_eq!(Angle32::DEG_90.half(), Angle32::DEG_45);
+        assert_eq!(Angle32::DEG_180.half(), Angle32::DEG_90);
+        // The main-range value is halved: -10° gives -5°, not 175°.
+        assert_float_eq!(
+            Angle32::from_degrees(350.0).half().to_degrees(),
+            -5.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_degrees(-10.0).half().to_degrees(),
+            -5.0,
+            abs <= 1e-4
+        );
+
+        for x in [-170.0, -90.0, -10.0, 0.0, 30.0, 135.0, 180.0] {
+            let angle = Angle64::from_degrees(x);
+            assert!(angle.half().double().approx_eq(angle, Angle64::EPSILON));
+        }
+    }
+
+    #[test]
+    fn angle_rotate_towards() {
+        let step = Angle32::from_degrees(10.0);
+
+        // Within reach: snaps to the target.
+        let target = Angle32::from_degrees(35.0);
+        assert_eq!(
+            Angle32::from_degrees(30.0).rotate_towards(target, step),
+            target
+        );
+        assert_eq!(target.rotate_towards(target, step), target);
+        assert_eq!(
+            Angle32::DEG_90.rotate_towards(Angle32::ZERO, Angle32::DEG_180),
+            Angle32::ZERO
+        );
+
+        // Partial step.
+        assert_float_eq!(
+            Angle32::ZERO
+                .rotate_towards(Angle32::DEG_90, step)
+                .to_degrees(),
+            10.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::ZERO
+                .rotate_towards(-Angle32::DEG_90, step)
+                .to_degrees(),
+            -10.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::ZERO
+                .rotate_towards(Angle32::DEG_90, -step)
+                .to_degrees(),
+            10.0,
+            abs <= 1e-4
+        );
+
+        // Crossing the seam.
+        assert_float_eq!(
+            Angle32::from_degrees(175.0)
+                .rotate_towards(Angle32::from_degrees(-150.0), step)
+                .to_degrees(),
+            -175.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_degrees(-175.0)
+                .rotate_towards(Angle32::from_degrees(150.0), step)
+                .to_degrees(),
+            175.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[test]
+    fn angle_inverse_lerp() {
+        let a = Angle32::from_degrees(10.0);
+        let b = Angle32::from_degrees(50.0);
+        assert_float_eq!(Angle32::inverse_lerp(a, b, a), 0.0, abs <= 1e-6);
+        assert_float_eq!(Angle32::inverse_lerp(a, b, b), 1.0, abs <= 1e-6);
+        assert_float_eq!(Angle32::inverse_lerp(a, b, a.midpoint(b)), 0.5, abs <= 1e-6);
+        assert_float_eq!(
+            Angle32::inverse_lerp(a, b, Angle32::from_degrees(70.0)),
+            1.5,
+            abs <= 1e-6
+        );
+        // Clockwise arc.
+        assert_float_eq!(
+            Angle32::inverse_lerp(b, a, Angle32::from_degrees(40.0)),
+            0.25,
+            abs <= 1e-6
+        );
+
+        // Across the ±180° boundary.
+        let a = Angle32::from_degrees(160.0);
+        let b = Angle32::from_degrees(-160.0);
+        assert_float_eq!(
+            Angle32::inverse_lerp(a, b, Angle32::DEG_180),
+            0.5,
+            abs <= 1e-6
+        );
+
+        for t in [0.0, 0.2, 0.5, 0.9, 1.0] {
+            assert_float_eq!(Angle32::inverse_lerp(a, b, a.lerp(b, t)), t, abs <= 1e-5);
+        }
+
+        assert!(!Angle32::inverse_lerp(a, a, b).is_finite());
+    }
+
+    #[test]
+    fn angle_remap() {
+        // A quarter arc onto a half arc.
+        let in_a = Angle32::ZERO;
+        let in_b = Angle32::DEG_90;
+        let out_a = -Angle32::DEG_90;
+        let out_b = Angle32::DEG_90;
+
+        for (x, expected) in [(0.0, -90.0), (45.0, 0.0), (60.0, 30.0), (90.0, 90.0)] {
+            let value = Angle32::from_degrees(x);
+            assert_float_eq!(
+                Angle32::remap(value, in_a, in_b, out_a, out_b).to_degrees(),
+                expected,
+                abs <= 1e-4
+            );
+        }
+    }
+
+    #[test]
+    fn angle_angle_to() {
+        let a = Angle32::from_degrees(170.0);
+        let b = Angle32::from_degrees(-170.0);
+        assert_float_eq!(a.angle_to(b).to_degrees(), 20.0, abs <= 1e-4);
+        assert_float_eq!(b.angle_to(a).to_degrees(), -20.0, abs <= 1e-4);
+
+        let a = Angle32::from_degrees(10.0);
+        let b = Angle32::from_degrees(350.0);
+        assert_float_eq!(a.angle_to(b).to_degrees(), -20.0, abs <= 1e-4);
+        assert_float_eq!(b.angle_to(a).to_degrees(), 20.0, abs <= 1e-4);
+
+        assert_eq!(Angle32::ZERO.angle_to(Angle32::DEG_180), Angle32::DEG_180);
+        assert_eq!(Angle32::DEG_180.angle_to(Angle32::ZERO), Angle32::DEG_180);
+    }
+
+    #[test]
+    fn angle_delta() {
+        let a = Angle64::from_degrees(10.0);
+        let b = Angle64::from_degrees(50.0);
+        assert_float_eq!(a.delta_degrees(b), 40.0, abs <= 1e-9);
+        assert_float_eq!(b.delta_degrees(a), -40.0, abs <= 1e-9);
+        assert_float_eq!(a.delta_radians(b), 40_f64.to_radians(), abs <= 1e-12);
+        assert_float_eq!(b.delta_radians(a), -(40_f64.to_radians()), abs <= 1e-12);
+
+        // Through the seam.
+        let a = Angle64::from_degrees(10.0);
+        let b = Angle64::from_degrees(350.0);
+        assert_float_eq!(a.delta_degrees(b), -20.0, abs <= 1e-9);
+        assert_float_eq!(b.delta_degrees(a), 20.0, abs <= 1e-9);
+
+        // Exactly half a turn is positive.
+        assert_float_eq!(
+            Angle64::ZERO.delta_degrees(Angle64::DEG_180),
+            180.0,
+            ulps <= 0
+        );
+        assert_float_eq!(
+            Angle64::DEG_180.delta_degrees(Angle64::ZERO),
+            180.0,
+            ulps <= 0
+        );
+        assert_float_eq!(
+            Angle64::DEG_90.delta_radians(-Angle64::DEG_90),
+            core::f64::consts::PI,
+            ulps <= 0
+        );
+    }
+
+    #[test]
+    fn angle_arc_length() {
+        assert_float_eq!(
+            Angle32::DEG_180.arc_length(1.0),
+            core::f32::consts::PI,
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            Angle32::DEG_90.arc_length(2.0),
+            core::f32::consts::PI,
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            (-Angle32::DEG_90).arc_length(2.0),
+            -core::f32::consts::PI,
+            abs <= 1e-6
+        );
+    }
+
+    #[test]
+    fn angle_sector_area() {
+        use core::f32::consts::{FRAC_PI_4, PI};
+
+        assert_float_eq!(Angle32::DEG_90.sector_area(1.0), FRAC_PI_4, abs <= 1e-6);
+        assert_float_eq!((-Angle32::DEG_90).sector_area(1.0), FRAC_PI_4, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_180.sector_area(2.0), 2.0 * PI, abs <= 1e-5);
+        assert_float_eq!(Angle32::ZERO.sector_area(2.0), 0.0, abs <= 1e-6);
+    }
+
+    #[test]
+    fn angle_angular_distance() {
+        let a = Angle32::from_degrees(170.0);
+        let b = Angle32::from_degrees(-170.0);
+        assert_float_eq!(a.angular_distance(b).to_degrees(), 20.0, abs <= 1e-4);
+        assert_float_eq!(b.angular_distance(a).to_degrees(), 20.0, abs <= 1e-4);
+
+        let a = Angle32::from_degrees(10.0);
+        let b = Angle32::from_degrees(350.0);
+        assert_float_eq!(a.angular_distance(b).to_degrees(), 20.0, abs <= 1e-4);
+        assert_float_eq!(b.angular_distance(a).to_degrees(), 20.0, abs <= 1e-4);
+
+        assert_eq!(
+            Angle32::DEG_90.angular_distance(Angle32::DEG_90),
+            Angle32::ZERO
+        );
+        assert_eq!(
+            Angle32::ZERO.angular_distance(Angle32::DEG_180),
+            Angle32::DEG_180
+        );
+        assert_eq!(
+            Angle32::DEG_90.angular_distance(-Angle32::DEG_90),
+            Angle32::DEG_180
+        );
+    }
+
+    #[test]
+    fn angle_approx_eq() {
+        let tolerance = Angle32::from_degrees(0.001);
+        assert!(Angle32::from_radians(core::f32::consts::PI)
+            .approx_eq(Angle32::from_radians(-core::f32::consts::PI), tolerance));
+        assert!(
+            Angle32::from_degrees(179.9998).approx_eq(Angle32::from_degrees(-179.9998), tolerance)
+        );
+        assert!(Angle32::DEG_90.approx_eq(Angle32::from_degrees(450.0), tolerance));
+        assert!(Angle32::DEG_90.approx_eq(Angle32::from_degrees(90.0005), -tolerance));
+
+        let tolerance = Angle32::from_degrees(0.5);
+        assert!(!Angle32::DEG_90.approx_eq(Angle32::from_degrees(91.0), tolerance));
+        assert!(!Angle32::from_degrees(179.5).approx_eq(Angle32::from_degrees(-179.5), tolerance));
+        assert!(Angle32::ZERO.approx_eq(Angle32::ZERO, Angle32::ZERO));
+    }
+
+    #[test]
+    fn angle_direction_relations() {
+        let tolerance = Angle32::from_degrees(1.0);
+        let a = Angle32::from_degrees(10.0);
+
+        assert!(a.is_parallel(Angle32::from_degrees(10.9), tolerance));
+        assert!(a.is_parallel(Angle32::from_degrees(9.1), -tolerance));
+        assert!(!a.is_parallel(Angle32::from_degrees(11.1), tolerance));
+        assert!(!a.is_parallel(Angle32::from_degrees(-170.0), tolerance));
+
+        assert!(a.is_antiparallel(Angle32::from_degrees(-170.9), tolerance));
+        assert!(a.is_antiparallel(Angle32::from_degrees(-169.1), tolerance));
+        assert!(!a.is_antiparallel(Angle32::from_degrees(-171.1), tolerance));
+        assert!(!a.is_antiparallel(a, tolerance));
+        // Across the ±180° boundary.
+        let b = Angle32::from_degrees(-0.5);
+        assert!(b.is_antiparallel(Angle32::from_degrees(179.9), tolerance));
+        assert!(b.is_antiparallel(Angle32::from_degrees(-179.9), tolerance));
+
+        assert!(a.is_perpendicular(Angle32::from_degrees(100.9), tolerance));
+        assert!(a.is_perpendicular(Angle32::from_degrees(-79.1), tolerance));
+        assert!(!a.is_perpendicular(Angle32::from_degrees(101.1), tolerance));
+        assert!(!a.is_perpendicular(Angle32::from_degrees(-78.9), tolerance));
+        assert!(!a.is_perpendicular(a, tolerance));
+        assert!(!a.is_perpendicular(a.opposite(), tolerance));
+    }
+
+    #[test]
+    fn angle_is_within_arc() {
+        let start = Angle32::from_degrees(170.0);
+        let end = Angle32::from_degrees(-170.0);
+
+        for x in [170.0, 175.0, 180.0, -175.0, -170.0] {
+            assert!(Angle32::from_degrees(x).is_within_arc(start, end));
+        }
+        for x in [175.0, 180.0, -175.0] {
+            assert!(!Angle32::from_degrees(x).is_within_arc(end, start));
+        }
+        for x in [0.0, 90.0, -90.0, 160.0, -160.0] {
+            assert!(!Angle32::from_degrees(x).is_within_arc(start, end));
+            assert!(Angle32::from_degrees(x).is_within_arc(end, start));
+        }
+
+        // Single point arc.
+        assert!(start.is_within_arc(start, start));
+        assert!(!end.is_within_arc(start, start));
+    }
+
+    #[test]
+    fn angle_clamp() {
+        let min = Angle32::from_degrees(170.0);
+        let max = Angle32::from_degrees(-170.0);
+
+        // Inside the arc.
+        for x in [170.0, 175.0, 180.0, -175.0, -170.0] {
+            let a = Angle32::from_degrees(x);
+            assert_eq!(a.clamp(min, max), a);
+        }
+
+        // Outside the arc.
+        assert_eq!(Angle32::DEG_90.clamp(min, max), min);
+        assert_eq!((-Angle32::DEG_90).clamp(min, max), max);
+        assert_eq!(Angle32::from_degrees(160.0).clamp(min, max), min);
+        assert_eq!(Angle32::from_degrees(-160.0).clamp(min, max), max);
+
+        // The arc is the other part of the circle.
+        assert_eq!(Angle32::ZERO.clamp(max, min), Angle32::ZERO);
+        assert_eq!(Angle32::from_degrees(175.0).clamp(max, min), min);
+        assert_eq!(Angle32::from_degrees(-175.0).clamp(max, min), max);
+
+        // Single point arc.
+        assert_eq!(Angle32::DEG_90.clamp(min, min), min);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn angle_smallest_enclosing_arc() {
+        macro_rules! check {
+            ([$($x:expr),*] => ($start:expr, $end:expr)) => {
+                let angles = [$($x),*].map(Angle64::from_degrees);
+                let (start, end) = Angle64::smallest_enclosing_arc(&angles).unwrap();
+                assert_float_eq!(start.to_degrees(), $start, abs <= 1e-9);
+                assert_float_eq!(end.to_degrees(), $end, abs <= 1e-9);
+                for angle in angles.into_iter().filter(|angle| !angle.is_nan()) {
+                    assert!(angle.is_within_arc(start, end));
+                }
+            };
+        }
+
+        // Clustered around the seam.
+        check!([175.0, -170.0, 170.0, 180.0, -175.0] => (170.0, -170.0));
+        // Clustered around zero.
+        check!([10.0, -20.0, 0.0, 5.0] => (-20.0, 10.0));
+        // The largest gap goes through the seam.
+        check!([0.0, 90.0, 135.0] => (0.0, 135.0));
+        // Duplicates.
+        check!([30.0, 30.0, 60.0] => (30.0, 60.0));
+        // Single angle.
+        check!([45.0] => (45.0, 45.0));
+        // NaN angles are ignored.
+        check!([f64::NAN, 10.0, f64::NAN, -20.0, 0.0] => (-20.0, 10.0));
+        check!([175.0, f64::NAN, -170.0] => (175.0, -170.0));
+
+        // Evenly spaced: all the gaps are equal, the one across ±180° is used.
+        check!([0.0, 90.0, 180.0, -90.0] => (-90.0, 180.0));
+        // Equal gaps except across ±180°: the one starting at the smallest angle is used.
+        let angles = [2.5, -2.5, 0.0].map(Angle64::from_radians);
+        let (start, end) = Angle64::smallest_enclosing_arc(&angles).unwrap();
+        assert_eq!((start, end), (Angle64::ZERO, Angle64::from_radians(-2.5)));
+
+        // The slice is left untouched.
+        let angles = [10.0, -20.0, 0.0].map(Angle32::from_degrees);
+        let _ = Angle32::smallest_enclosing_arc(&angles);
+        assert_eq!(angles, [10.0, -20.0, 0.0].map(Angle32::from_degrees));
+
+        assert_eq!(Angle32::smallest_enclosing_arc(&[]), None);
+        assert_eq!(
+            Angle32::smallest_enclosing_arc(&[Angle32::from_radians(f32::NAN); 3]),
+            None
+        );
+    }
+
+    #[test]
+    fn angle_to_positive() {
+        assert_float_eq!(Angle32::ZERO.to_radians_positive(), 0.0, ulps <= 0);
+        assert_float_eq!(
+            Angle32::RAD_PI.to_radians_positive(),
+            core::f32::consts::PI,
+            ulps <= 0
+        );
+        assert_float_eq!(
+            (-Angle32::DEG_90).to_radians_positive(),
+            3.0 * core::f32::consts::FRAC_PI_2,
+            abs <= 1e-6
+        );
+
+        let just_below_zero = Angle32::from_radians(-f32::EPSILON);
+        assert!(just_below_zero.to_radians_positive() < core::f32::consts::TAU);
+        assert!(just_below_zero.to_degrees_positive() < 360.0);
+        assert!(just_below_zero.to_turns_positive() < 1.0);
+        assert!(just_below_zero.to_gradians_positive() < 400.0);
+
+        let tiny_below_zero = Angle32::from_radians(-1e-10);
+        assert!(tiny_below_zero.to_radians_positive() < core::f32::consts::TAU);
+        assert!(tiny_below_zero.to_degrees_positive() < 360.0);
+        assert!(tiny_below_zero.to_turns_positive() < 1.0);
+        assert!(tiny_below_zero.to_gradians_positive() < 400.0);
+
+        assert_float_eq!(Angle32::DEG_180.to_degrees_positive(), 180.0, abs <= 1e-4);
+        assert_float_eq!((-Angle32::DEG_90).to_degrees_positive(), 270.0, abs <= 1e-4);
+        assert_float_eq!((-Angle32::DEG_90).to_turns_positive(), 0.75, abs <= 1e-6);
+        assert_float_eq!(
+            (-Angle32::DEG_90).to_gradians_positive(),
+            300.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[test]
+    fn angle_wrap_into() {
+        use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+        let angles = [0.0, 1.0, -1.0, FRAC_PI_2, -FRAC_PI_2, PI, 3.0, -3.0];
+
+        for lower in [0.0, -PI, FRAC_PI_4, 10.0 * TAU] {
+            for radians in angles {
+                let angle = Angle32::from_radians(radians);
+                let value = angle.wrap_into(lower);
+
+                assert!(lower <= value && value < lower + TAU);
+                assert_float_eq!(
+                    Angle32::from_radians(value)
+                        .angular_distance(angle)
+                        .to_radians(),
+                    0.0,
+                    abs <= 1e-5
+                );
+            }
+        }
+
+        assert_float_eq!(Angle32::DEG_180.wrap_into(-PI), -PI, abs <= 1e-6);
+        assert_float_eq!(Angle32::ZERO.wrap_into(FRAC_PI_4), TAU, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_90.wrap_into(FRAC_PI_4), FRAC_PI_2, abs <= 1e-6);
+    }
+
+    #[test]
+    fn angle_quadrant() {
+        let deg_135 = Angle32::DEG_90 + Angle32::DEG_45;
+
+        assert_eq!(Angle32::ZERO.quadrant(), 1);
+        assert_eq!(Angle32::DEG_45.quadrant(), 1);
+        assert_eq!(Angle32::DEG_90.quadrant(), 2);
+        assert_eq!(deg_135.quadrant(), 2);
+        assert_eq!(Angle32::DEG_180.quadrant(), 3);
+        assert_eq!((-deg_135).quadrant(), 3);
+        assert_eq!((-Angle32::DEG_90).quadrant(), 4);
+        assert_eq!((-Angle32::DEG_45).quadrant(), 4);
+        assert_eq!(Angle32::from_radians(-f32::EPSILON).quadrant(), 4);
+    }
+
+    #[test]
+    fn angle_octant() {
+        let deg_135 = Angle32::DEG_90 + Angle32::DEG_45;
+
+        assert_eq!(Angle32::ZERO.octant(), 1);
+        assert_eq!(Angle32::DEG_45.octant(), 2);
+        assert_eq!(Angle32::DEG_90.octant(), 3);
+        assert_eq!(deg_135.octant(), 4);
+        assert_eq!(Angle32::DEG_180.octant(), 5);
+        assert_eq!((-deg_135).octant(), 6);
+        assert_eq!((-Angle32::DEG_90).octant(), 7);
+        assert_eq!((-Angle32::DEG_45).octant(), 8);
+        assert_eq!(Angle32::from_degrees(-170.0).octant(), 5);
+        assert_eq!(Angle32::from_degrees(10.0).octant(), 1);
+    }
+
+    #[test]
+    fn angle_classification() {
+        use core::f32::consts::{FRAC_PI_2, PI};
+
+        let off = 4.0 * f32::EPSILON;
+
+        macro_rules! check {
+            ($radians:expr => [$acute:expr, $right:expr, $obtuse:expr, $straight:expr]) => {
+                for angle in [
+                    Angle32::from_radians($radians),
+                    -Angle32::from_radians($radians),
+                ] {
+                    assert_eq!(angle.is_acute(), $acute, "is_acute({angle:?})");
+                    assert_eq!(angle.is_right(), $right, "is_right({angle:?})");
+                    assert_eq!(angle.is_obtuse(), $obtuse, "is_obtuse({angle:?})");
+                    assert_eq!(angle.is_straight(), $straight, "is_straight({angle:?})");
+                }
+            };
+        }
+
+        check!(0.0 => [false, false, false, false]);
+        check!(0.5 => [true, false, false, false]);
+        check!(FRAC_PI_2 - off => [true, false, false, false]);
+        check!(FRAC_PI_2 - f32::EPSILON => [false, true, false, false]);
+        check!(FRAC_PI_2 => [false, true, false, false]);
+        check!(FRAC_PI_2 + f32::EPSILON => [false, true, false, false]);
+        check!(FRAC_PI_2 + off => [false, false, true, false]);
+        check!(2.0 => [false, false, true, false]);
+        check!(PI - off => [false, false, true, false]);
+        check!(PI - f32::EPSILON => [false, false, false, true]);
+        check!(PI => [false, false, false, true]);
+
+        assert!(Angle32::from_degrees(90.0).is_right());
+        assert!(Angle32::from_degrees(-90.0).is_right());
+        assert!(Angle32::from_degrees(180.0).is_straight());
+        assert!(Angle32::from_degrees(-180.0).is_straight());
+    }
+
+    #[test]
+    fn angle_snap_to() {
+        let step = Angle32::from_degrees(15.0);
+
+        macro_rules! check {
+            ($value:expr => $expected:expr) => {
+                assert_float_eq!(
+                    Angle32::from_degrees($value).snap_to(step).to_degrees(),
+                    $expected,
+                    abs <= 1e-4
+                );
+            };
+        }
+
+        check!(22.0 => 15.0);
+        check!(23.0 => 30.0);
+        check!(-22.0 => -15.0);
+        check!(-23.0 => -30.0);
+        check!(0.0 => 0.0);
+        check!(179.0 => 180.0);
+        check!(-179.0 => 180.0);
+
+        assert_float_eq!(
+            Angle32::from_degrees(23.0).snap_to(-step).to_degrees(),
+            30.0,
+            abs <= 1e-4
+        );
+
+        let a = Angle32::from_degrees(23.0);
+        assert_eq!(a.snap_to(Angle32::ZERO), a);
     }
-}
 
-forward_ref_binop!(impl<F: Float> Sub, sub for Angle<F>, Angle<F>);
+    #[test]
+    fn angle_subdivide() {
+        let start = Angle32::from_degrees(-45.0);
+        let end = Angle32::from_degrees(45.0);
 
-impl<F: Float> SubAssign for Angle<F> {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+        assert_eq!(Angle32::subdivide(start, end, 5).count(), 5);
+        for (angle, expected) in
+            Angle32::subdivide(start, end, 5).zip([-45.0, -22.5, 0.0, 22.5, 45.0])
+        {
+            assert_float_eq!(angle.to_degrees(), expected, abs <= 1e-4);
+        }
+        for (a, b) in
+            Angle32::subdivide(start, end, 5).zip(Angle32::subdivide(start, end, 5).skip(1))
+        {
+            assert_float_eq!(a.angle_to(b).to_degrees(), 22.5, abs <= 1e-4);
+        }
+
+        // Across the seam.
+        assert_eq!(Angle32::subdivide(end, start, 4).count(), 4);
+        for (angle, expected) in Angle32::subdivide(end, start, 4).zip([45.0, 135.0, -135.0, -45.0])
+        {
+            assert_float_eq!(angle.to_degrees(), expected, abs <= 1e-4);
+        }
+
+        let mut angles = Angle32::subdivide(start, end, 1);
+        assert_eq!(angles.next(), Some(start));
+        assert_eq!(angles.next(), None);
+        assert_eq!(Angle32::subdivide(start, end, 0).count(), 0);
     }
-}
 
-forward_ref_op_assign!(impl<F: Float> SubAssign, sub_assign for Angle<F>, Angle<F>);
+    #[test]
+    fn angle_circle_steps() {
+        assert_eq!(Angle32::circle_steps(4).count(), 4);
+        for (angle, expected) in Angle32::circle_steps(4).zip([0.0, 90.0, 180.0, -90.0]) {
+            assert_float_eq!(angle.to_degrees(), expected, abs <= 1e-4);
+        }
 
-impl<F: Float> Mul<F> for Angle<F> {
-    type Output = Self;
+        assert_eq!(Angle32::circle_steps(7).count(), 7);
+        for (a, b) in Angle32::circle_steps(7).zip(Angle32::circle_steps(7).skip(1)) {
+            assert_float_eq!(a.angle_to(b).to_turns(), 1.0 / 7.0, abs <= 1e-6);
+        }
 
-    #[inline]
-    fn mul(self, rhs: F) -> Self::Output {
-        Self::from_radians(self.radians * rhs)
+        let mut angles = Angle32::circle_steps(1);
+        assert_eq!(angles.next(), Some(Angle32::ZERO));
+        assert_eq!(angles.next(), None);
+        assert_eq!(Angle32::circle_steps(0).count(), 0);
     }
-}
 
-forward_ref_binop!(impl<F: Float> Mul, mul for Angle<F>, F);
+    #[test]
+    fn angle_polygon() {
+        assert_float_eq!(Angle32::polygon_interior(3).to_degrees(), 60.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::polygon_interior(4).to_degrees(), 90.0, abs <= 1e-4);
+        assert_float_eq!(
+            Angle32::polygon_interior(6).to_degrees(),
+            120.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::polygon_exterior(3).to_degrees(),
+            120.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(Angle32::polygon_exterior(4).to_degrees(), 90.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::polygon_exterior(6).to_degrees(), 60.0, abs <= 1e-4);
 
-impl Mul<Angle<f32>> for f32 {
-    type Output = Angle<f32>;
+        for n in 3..20 {
+            let sum = Angle64::polygon_interior(n).to_radians()
+                + Angle64::polygon_exterior(n).to_radians();
+            assert_float_eq!(sum, core::f64::consts::PI, abs <= 1e-12);
+        }
 
-    #[inline]
-    fn mul(self, rhs: Angle<f32>) -> Self::Output {
-        rhs * self
+        for n in 0..3 {
+            assert!(Angle32::polygon_interior(n).is_nan());
+            assert!(Angle32::polygon_exterior(n).is_nan());
+        }
     }
-}
 
-forward_ref_binop!(impl Mul, mul for f32, Angle<f32>);
+    #[test]
+    fn angle_quantize() {
+        assert_eq!(Angle32::ZERO.quantize(8), 0);
+        assert_eq!(Angle32::from_degrees(44.9).quantize(8), 0);
+        assert_eq!(Angle32::from_degrees(45.1).quantize(8), 1);
+        assert_eq!(Angle32::DEG_180.quantize(8), 4);
+        assert_eq!(Angle32::from_degrees(-0.1).quantize(8), 7);
+        assert_eq!(Angle32::from_radians(-1e-30).quantize(8), 0);
+        assert_eq!(Angle32::from_radians(f32::NAN).quantize(8), 0);
+        assert_eq!(Angle32::from_degrees(123.0).quantize(1), 0);
 
-impl Mul<Angle<f64>> for f64 {
-    type Output = Angle<f64>;
+        let n = 12;
+        let half_width = 360.0 / 24.0;
+        for x in [-179.0, -90.0, -12.5, 0.0, 7.0, 89.0, 179.5, 180.0] {
+            let a = Angle32::from_degrees(x);
+            let index = a.quantize(n);
+            assert!(index < n);
+            let center = Angle32::from_bucket(index, n);
+            assert!(a.approx_eq(center, Angle32::from_degrees(half_width + 1e-4)));
+        }
+    }
 
-    #[inline]
-    fn mul(self, rhs: Angle<f64>) -> Self::Output {
-        rhs * self
+    #[test]
+    #[should_panic = "the number of buckets must be positive"]
+    fn angle_quantize_zero_buckets() {
+        let _ = Angle32::ZERO.quantize(0);
     }
-}
 
-forward_ref_binop!(impl Mul, mul for f64, Angle<f64>);
+    #[test]
+    fn angle_from_degrees_slice() {
+        let degrees = [
+            0.0,
+            45.0,
+            90.0,
+            180.0,
+            -180.0,
+            270.0,
+            -725.5,
+            1e6,
+            0.001,
+            f32::NAN,
+        ];
+        let mut angles = [Angle32::ZERO; 10];
 
-impl<F: Float> MulAssign<F> for Angle<F> {
-    #[inline]
-    fn mul_assign(&mut self, rhs: F) {
-        *self = *self * rhs;
-    }
-}
+        Angle32::from_degrees_slice(&degrees, &mut angles);
 
-forward_ref_op_assign!(impl<F: Float> MulAssign, mul_assign for Angle<F>, F);
+        for (angle, degrees) in angles.iter().zip(degrees) {
+            let expected = Angle32::from_degrees(degrees);
+            if expected.is_nan() {
+                assert!(angle.is_nan());
+            } else {
+                assert_eq!(*angle, expected);
+            }
+        }
 
-impl<F: Float> Div<F> for Angle<F> {
-    type Output = Self;
+        Angle64::from_degrees_slice(&[], &mut []);
+    }
 
-    #[inline]
-    fn div(self, rhs: F) -> Self::Output {
-        Self::from_radians(self.radians / rhs)
+    #[test]
+    #[should_panic = "source and destination slices have different lengths"]
+    fn angle_from_degrees_slice_length_mismatch() {
+        let mut angles = [Angle32::ZERO; 2];
+        Angle32::from_degrees_slice(&[1.0, 2.0, 3.0], &mut angles);
     }
-}
 
-forward_ref_binop!(impl<F: Float> Div, div for Angle<F>, F);
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_sin_cos_slice() {
+        let angles = [
+            Angle64::ZERO,
+            Angle64::DEG_30,
+            Angle64::DEG_90,
+            Angle64::DEG_180,
+            -Angle64::DEG_45,
+            Angle64::from_degrees(123.4),
+        ];
+        let mut values = [(0.0, 0.0); 6];
 
-impl<F: Float> DivAssign<F> for Angle<F> {
-    #[inline]
-    fn div_assign(&mut self, rhs: F) {
-        *self = *self / rhs;
+        Angle64::sin_cos_slice(&angles, &mut values);
+
+        for (angle, (sin, cos)) in angles.iter().zip(values) {
+            let (expected_sin, expected_cos) = angle.sin_cos();
+            assert_float_eq!(sin, expected_sin, ulps <= 0);
+            assert_float_eq!(cos, expected_cos, ulps <= 0);
+        }
     }
-}
 
-forward_ref_op_assign!(impl<F: Float> DivAssign, div_assign for Angle<F>, F);
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    #[should_panic = "source and destination slices have different lengths"]
+    fn angle_sin_cos_slice_length_mismatch() {
+        let mut values = [(0.0, 0.0); 1];
+        Angle32::sin_cos_slice(&[Angle32::ZERO, Angle32::DEG_90], &mut values);
+    }
 
-impl<F: Float> Neg for Angle<F> {
-    type Output = Self;
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn angle_unwrap_sequence() {
+        use alloc::vec::Vec;
 
-    #[inline]
-    fn neg(self) -> Self::Output {
-        debug_assert!(self.radians.is_nan() || (-F::PI < self.radians && self.radians <= F::PI));
-        if self.radians == F::PI {
-            self
-        } else {
-            Self::from_radians_unchecked(-self.radians)
+        let angles: Vec<_> = (0..40)
+            .map(|i| Angle64::from_degrees(150.0 + 10.0 * f64::from(i)))
+            .collect();
+
+        let unwrapped = Angle64::unwrap_sequence(&angles);
+
+        assert_eq!(unwrapped.len(), angles.len());
+        for (i, angle) in unwrapped.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let expected = 150.0 + 10.0 * i as f64;
+            assert_float_eq!(angle.to_degrees(), expected, abs <= 1e-9);
+        }
+        assert!(unwrapped
+            .windows(2)
+            .all(|w| w[0].to_radians() < w[1].to_radians()));
+
+        let angles = [-170.0, 180.0, 170.0, -180.0].map(Angle32::from_degrees);
+        let unwrapped = Angle32::unwrap_sequence(&angles);
+        let expected = [-170.0, -180.0, -190.0, -180.0];
+        for (angle, expected) in unwrapped.iter().zip(expected) {
+            assert_float_eq!(angle.to_degrees(), expected, abs <= 1e-3);
+        }
+
+        assert!(Angle32::unwrap_sequence(&[]).is_empty());
+        assert_eq!(
+            Angle32::unwrap_sequence(&[Angle32::DEG_90]),
+            [AngleUnbounded32::DEG_90]
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_sin_cos_fast() {
+        for angle in Angle64::circle_steps(3600) {
+            assert_float_eq!(angle.sin_fast(), angle.sin(), abs <= 0.0017);
+            assert_float_eq!(angle.cos_fast(), angle.cos(), abs <= 0.0017);
+        }
+
+        for angle in Angle32::circle_steps(3600) {
+            assert_float_eq!(angle.sin_fast(), angle.sin(), abs <= 0.0017);
+            assert_float_eq!(angle.cos_fast(), angle.cos(), abs <= 0.0017);
         }
+
+        assert_float_eq!(Angle64::ZERO.sin_fast(), 0.0, abs <= 1e-15);
+        assert_float_eq!(Angle64::DEG_90.sin_fast(), 1.0, abs <= 1e-15);
+        assert_float_eq!((-Angle64::DEG_90).sin_fast(), -1.0, abs <= 1e-15);
+        assert_float_eq!(Angle64::DEG_180.cos_fast(), -1.0, abs <= 1e-15);
     }
-}
 
-forward_ref_unop!(impl<F: Float> Neg, neg for Angle<F>);
+    #[test]
+    fn angle_bam_u16() {
+        assert_eq!(Angle32::ZERO.to_bam_u16(), 0);
+        assert_eq!(Angle32::DEG_90.to_bam_u16(), 0x4000);
+        assert_eq!(Angle32::DEG_180.to_bam_u16(), 0x8000);
+        assert_eq!((-Angle32::DEG_90).to_bam_u16(), 0xC000);
+        assert_eq!(Angle32::from_radians(-1e-10).to_bam_u16(), 0);
+        assert_eq!(Angle32::from_radians(f32::NAN).to_bam_u16(), 0);
 
-impl<F: Float + Sum> Sum for Angle<F> {
-    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        Angle::from_radians(iter.map(|x| x.radians).sum())
+        for bits in [0, 1, 0x1234, 0x7FFF, 0x8000, 0x8001, 0xFFFF] {
+            assert_eq!(Angle32::from_bam_u16(bits).to_bam_u16(), bits);
+            assert_eq!(Angle64::from_bam_u16(bits).to_bam_u16(), bits);
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use float_eq::assert_float_eq;
+    #[test]
+    fn angle_bam_u32() {
+        assert_eq!(Angle64::ZERO.to_bam_u32(), 0);
+        assert_eq!(Angle64::DEG_90.to_bam_u32(), 0x4000_0000);
+        assert_eq!(Angle64::DEG_180.to_bam_u32(), 0x8000_0000);
+        assert_eq!((-Angle64::DEG_90).to_bam_u32(), 0xC000_0000);
 
-    use crate::{Angle, Angle32};
+        for bits in [0, 1, 0x1234_5678, 0x7FFF_FFFF, 0x8000_0000, 0xFFFF_FFFF] {
+            assert_eq!(Angle64::from_bam_u32(bits).to_bam_u32(), bits);
+        }
+    }
 
     #[test]
-    fn angle_pi_eq_neg_pi() {
-        assert_eq!(
-            Angle::from_radians(core::f32::consts::PI),
-            Angle::from_radians(-core::f32::consts::PI),
+    fn angle_bearing() {
+        // North is up.
+        assert_float_eq!(Angle32::from_bearing(0.0).to_degrees(), 90.0, abs <= 1e-4);
+        // East is right.
+        assert_float_eq!(Angle32::from_bearing(90.0).to_degrees(), 0.0, abs <= 1e-4);
+        // South is down.
+        assert_float_eq!(
+            Angle32::from_bearing(180.0).to_degrees(),
+            -90.0,
+            abs <= 1e-4
+        );
+        // West is left.
+        assert_float_eq!(
+            Angle32::from_bearing(270.0)
+                .angular_distance(Angle32::DEG_180)
+                .to_degrees(),
+            0.0,
+            abs <= 1e-4
+        );
+
+        for bearing in [0.0, 10.0, 90.0, 135.0, 180.0, 270.0, 359.0] {
+            assert_float_eq!(
+                Angle32::from_bearing(bearing).to_bearing(),
+                bearing,
+                abs <= 1e-4
+            );
+        }
+
+        assert_float_eq!(
+            Angle32::from_bearing(-10.0).to_bearing(),
+            350.0,
+            abs <= 1e-4
         );
+        assert_float_eq!(Angle32::from_bearing(370.0).to_bearing(), 10.0, abs <= 1e-4);
     }
 
     #[test]
-    fn angle_sum_is_accurate() {
-        const ANGLES: [f32; 20] = [
-            0.711_889,
-            0.612_456_56,
-            -1.165_211_3,
-            -1.452_307,
-            -0.587_785_5,
-            0.593_006_5,
-            0.012_860_533,
-            -1.142_349_6,
-            -1.302_776_9,
-            0.510_909_6,
-            2.365_249_2,
-            1.743_016_8,
-            1.165_635_7,
-            -2.191_822_8,
-            2.505_914_4,
-            0.498_677,
-            2.496_595,
-            -0.108_386_315,
-            0.991_436_9,
-            -2.835_525_5,
-        ];
+    fn angle_float_predicates() {
+        let zero = Angle32::ZERO;
+        assert!(zero.is_finite());
+        assert!(zero.is_sign_positive());
+        assert!(!zero.is_sign_negative());
 
-        let angles = ANGLES.map(Angle32::from_radians);
+        let positive = Angle32::from_degrees(90.0);
+        assert!(positive.is_finite());
+        assert!(positive.is_sign_positive());
+        assert!(!positive.is_sign_negative());
 
-        let sum: Angle32 = angles.iter().copied().sum();
-        let add = angles.iter().copied().fold(Angle::ZERO, |a, b| a + b);
+        let negative = Angle32::from_degrees(270.0);
+        assert!(negative.is_finite());
+        assert!(!negative.is_sign_positive());
+        assert!(negative.is_sign_negative());
 
-        assert_float_eq!(sum.to_radians(), add.to_radians(), abs <= 1e-5);
+        assert!(Angle32::DEG_180.is_sign_positive());
+
+        let nan = Angle32::from_radians(f32::NAN);
+        assert!(!nan.is_finite());
+        assert!(!Angle32::from_radians(f32::INFINITY).is_finite());
+    }
+
+    #[test]
+    fn angle_copysign() {
+        let a = Angle64::from_degrees(30.0);
+        assert_eq!(a.copysign(-1.0), -a);
+        assert_eq!(a.copysign(1.0), a);
+        assert_eq!((-a).copysign(0.5), a);
+        assert_eq!((-a).copysign(-0.0), -a);
+        assert_eq!(Angle64::DEG_180.copysign(-1.0), Angle64::DEG_180);
+        assert!(Angle64::ZERO.copysign(-1.0).is_sign_negative());
+        assert!(Angle64::from_radians(f64::NAN).copysign(1.0).is_nan());
     }
 
     #[test]
@@ -540,6 +3794,10 @@ mod tests {
                     assert!(Angle::from_degrees($nan).is_nan());
                     assert!(Angle::from_turns($nan).is_nan());
                     assert!(Angle::from_gradians($nan).is_nan());
+                    assert!(Angle::from_arcminutes($nan).is_nan());
+                    assert!(Angle::from_arcseconds($nan).is_nan());
+                    assert!(Angle::from_mils($nan).is_nan());
+                    assert!(Angle::from_hours($nan).is_nan());
                 )*
             };
         }
@@ -557,6 +3815,10 @@ mod tests {
                     assert!(Angle::from_degrees($inf).is_nan());
                     assert!(Angle::from_turns($inf).is_nan());
                     assert!(Angle::from_gradians($inf).is_nan());
+                    assert!(Angle::from_arcminutes($inf).is_nan());
+                    assert!(Angle::from_arcseconds($inf).is_nan());
+                    assert!(Angle::from_mils($inf).is_nan());
+                    assert!(Angle::from_hours($inf).is_nan());
                 )*
             };
         }
@@ -579,9 +3841,349 @@ mod tests {
                     assert!(!Angle::from_degrees($big_value).is_nan());
                     assert!(!Angle::from_turns($big_value).is_nan());
                     assert!(!Angle::from_gradians($big_value).is_nan());
+                    assert!(!Angle::from_arcminutes($big_value).is_nan());
+                    assert!(!Angle::from_arcseconds($big_value).is_nan());
+                    assert!(!Angle::from_mils($big_value).is_nan());
+                    assert!(!Angle::from_hours($big_value).is_nan());
                 )*
             };
         }
         test!(f32::MAX, f32::MIN, f64::MAX, f64::MIN);
     }
+
+    #[test]
+    fn angle_fixed_point_roundtrip() {
+        const GPS_SCALE: u64 = 10_000_000;
+        const ARCSEC_SCALE: u64 = 3600;
+
+        for bits in [0, 1, -1, 451_234_567, -1_799_999_999, 1_800_000_000] {
+            let angle = Angle::<f64>::from_fixed_point(bits, GPS_SCALE);
+            assert_eq!(angle.to_fixed_point(GPS_SCALE), bits);
+        }
+
+        for bits in [0, 1, -1, 162_000, -647_999, 648_000] {
+            let angle = Angle::<f32>::from_fixed_point(bits, ARCSEC_SCALE);
+            assert_eq!(angle.to_fixed_point(ARCSEC_SCALE), bits);
+        }
+    }
+
+    #[test]
+    fn angle_fixed_point_rounds() {
+        assert_eq!(Angle::from_degrees(0.6_f64).to_fixed_point(1), 1);
+        assert_eq!(Angle::from_degrees(-0.6_f64).to_fixed_point(1), -1);
+        assert_eq!(Angle::from_degrees(0.4_f64).to_fixed_point(1), 0);
+        assert_eq!(Angle::from_degrees(-0.4_f64).to_fixed_point(1), 0);
+    }
+
+    #[test]
+    fn angle_fixed_point_saturates() {
+        assert_eq!(Angle::<f64>::DEG_90.to_fixed_point(u64::MAX), i64::MAX);
+        assert_eq!((-Angle::<f64>::DEG_90).to_fixed_point(u64::MAX), i64::MIN);
+        assert_eq!(Angle::from_radians(f64::NAN).to_fixed_point(1), 0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_from_atan2() {
+        assert_float_eq!(
+            Angle32::from_atan2(1.0, 1.0).to_degrees(),
+            45.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_atan2(1.0, -1.0).to_degrees(),
+            135.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_atan2(-1.0, -1.0).to_degrees(),
+            -135.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_atan2(-1.0, 1.0).to_degrees(),
+            -45.0,
+            abs <= 1e-4
+        );
+
+        assert_eq!(Angle32::from_atan2(0.0, 0.0), Angle32::ZERO);
+        assert_eq!(Angle32::from_atan2(0.0, -0.0), Angle32::ZERO);
+        assert_eq!(Angle32::from_atan2(-0.0, -0.0), Angle32::ZERO);
+        assert_eq!(Angle64::from_atan2(-0.0, 0.0), Angle64::ZERO);
+        assert_eq!(Angle32::from_atan2(-0.0, -1.0), Angle32::DEG_180);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_reciprocal_trigonometry() {
+        let sqrt_2 = core::f32::consts::SQRT_2;
+
+        assert_float_eq!(Angle32::DEG_45.sec(), sqrt_2, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_45.csc(), sqrt_2, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_45.cot(), 1.0, abs <= 1e-6);
+
+        assert!(Angle32::ZERO.csc().is_infinite());
+        assert!(Angle32::ZERO.cot().is_infinite());
+        assert!(Angle32::DEG_90.sec().abs() > 1e6);
+        assert!(Angle32::DEG_180.csc().abs() > 1e6);
+        assert_float_eq!(Angle32::DEG_90.cot(), 0.0, abs <= 1e-6);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_versine_and_haversine() {
+        assert_float_eq!(Angle32::ZERO.versine(), 0.0, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_60.versine(), 0.5, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_180.versine(), 2.0, abs <= 1e-6);
+
+        assert_float_eq!(Angle32::ZERO.coversine(), 1.0, abs <= 1e-6);
+        assert_float_eq!(
+            Angle32::DEG_60.coversine(),
+            1.0 - 0.75_f32.sqrt(),
+            abs <= 1e-6
+        );
+        assert_float_eq!(Angle32::DEG_90.coversine(), 0.0, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_180.coversine(), 1.0, abs <= 1e-6);
+
+        assert_float_eq!(Angle32::ZERO.haversine(), 0.0, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_60.haversine(), 0.25, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_180.haversine(), 1.0, abs <= 1e-6);
+
+        // No cancellation near zero.
+        let tiny = Angle32::from_radians(1e-4);
+        assert_float_eq!(tiny.versine(), 5e-9, rmax <= 1e-4);
+        assert_float_eq!(tiny.haversine(), 2.5e-9, rmax <= 1e-4);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_chord_length() {
+        assert_float_eq!(Angle32::DEG_180.chord_length(1.0), 2.0, abs <= 1e-6);
+        assert_float_eq!(Angle32::DEG_60.chord_length(3.0), 3.0, abs <= 1e-6);
+        assert_float_eq!((-Angle32::DEG_60).chord_length(3.0), 3.0, abs <= 1e-6);
+        assert_float_eq!(Angle32::ZERO.chord_length(3.0), 0.0, abs <= 1e-6);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_segment_area() {
+        use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+        assert_float_eq!(
+            Angle32::DEG_90.segment_area(1.0),
+            FRAC_PI_4 - 0.5,
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            (-Angle32::DEG_90).segment_area(1.0),
+            FRAC_PI_4 - 0.5,
+            abs <= 1e-6
+        );
+        // A half disc.
+        assert_float_eq!(Angle32::DEG_180.segment_area(1.0), FRAC_PI_2, abs <= 1e-6);
+        assert_float_eq!(Angle32::ZERO.segment_area(1.0), 0.0, abs <= 1e-6);
+
+        let angle = Angle64::from_degrees(75.0);
+        let triangle = 0.5 * 9.0 * angle.sin();
+        assert_float_eq!(
+            angle.segment_area(3.0),
+            angle.sector_area(3.0) - triangle,
+            abs <= 1e-12
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_to_vector() {
+        macro_rules! check {
+            ($angle:expr, ($x:expr, $y:expr)) => {
+                let (x, y) = $angle.to_vector();
+                assert_float_eq!(x, $x, abs <= 1e-6);
+                assert_float_eq!(y, $y, abs <= 1e-6);
+            };
+        }
+
+        check!(Angle32::ZERO, (1.0, 0.0));
+        check!(Angle32::DEG_90, (0.0, 1.0));
+        check!(Angle32::DEG_180, (-1.0, 0.0));
+        check!(-Angle32::DEG_90, (0.0, -1.0));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_to_rotation_matrix() {
+        let [row_x, row_y] = Angle32::DEG_90.to_rotation_matrix();
+        let vector = [1.0, 0.0];
+
+        let rotated_x = row_x[0] * vector[0] + row_x[1] * vector[1];
+        let rotated_y = row_y[0] * vector[0] + row_y[1] * vector[1];
+
+        assert_float_eq!(rotated_x, 0.0, abs <= 1e-6);
+        assert_float_eq!(rotated_y, 1.0, abs <= 1e-6);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_rotate_vector() {
+        macro_rules! check {
+            ($angle:expr, ($x:expr, $y:expr)) => {
+                let (x, y) = $angle.rotate_vector((1.0, 0.0));
+                assert_float_eq!(x, $x, abs <= 1e-6);
+                assert_float_eq!(y, $y, abs <= 1e-6);
+            };
+        }
+
+        check!(Angle32::DEG_90, (0.0, 1.0));
+        check!(Angle32::DEG_180, (-1.0, 0.0));
+        check!(-Angle32::DEG_90, (0.0, -1.0));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_inverse_trigonometry() {
+        assert_float_eq!(Angle32::asin(1.0).to_degrees(), 90.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::asin(-1.0).to_degrees(), -90.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::asin(0.5).to_degrees(), 30.0, abs <= 1e-4);
+        assert!(Angle32::asin(2.0).is_nan());
+
+        assert_float_eq!(Angle32::acos(1.0).to_degrees(), 0.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::acos(-1.0).to_degrees(), 180.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::acos(0.5).to_degrees(), 60.0, abs <= 1e-4);
+        assert!(Angle32::acos(2.0).is_nan());
+
+        assert_float_eq!(Angle32::atan(1.0).to_degrees(), 45.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::atan(-1.0).to_degrees(), -45.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::atan(f32::INFINITY).to_degrees(), 90.0, abs <= 1e-4);
+        assert!(Angle32::atan(f32::NAN).is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_percent_grade() {
+        assert_float_eq!(Angle32::DEG_45.to_percent_grade(), 100.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::ZERO.to_percent_grade(), 0.0, abs <= 1e-6);
+        assert_float_eq!((-Angle32::DEG_45).to_percent_grade(), -100.0, abs <= 1e-4);
+        assert!(Angle64::DEG_90.to_percent_grade() > 1e15);
+
+        assert_float_eq!(
+            Angle32::from_percent_grade(100.0).to_degrees(),
+            45.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_percent_grade(0.0).to_degrees(),
+            0.0,
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            Angle32::from_percent_grade(f32::INFINITY).to_degrees(),
+            90.0,
+            abs <= 1e-4
+        );
+
+        let angle = Angle64::from_percent_grade(12.5);
+        assert_float_eq!(angle.to_percent_grade(), 12.5, abs <= 1e-9);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_slope() {
+        assert_float_eq!(Angle32::from_slope(1.0).to_degrees(), 45.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::from_slope(-1.0).to_degrees(), -45.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::DEG_45.to_slope(), 1.0, abs <= 1e-4);
+
+        assert_float_eq!(
+            Angle32::from_slope_components(1.0, 1.0).to_degrees(),
+            45.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_slope_components(1.0, 0.0).to_degrees(),
+            90.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_slope_components(-1.0, 0.0).to_degrees(),
+            -90.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            Angle32::from_slope_components(-1.0, -1.0).to_degrees(),
+            -135.0,
+            abs <= 1e-4
+        );
+
+        let angle = Angle64::from_slope(0.25);
+        assert_float_eq!(angle.to_slope(), 0.25, abs <= 1e-12);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_bisector_of() {
+        let a = Angle32::from_degrees(10.0);
+        let b = Angle32::from_degrees(50.0);
+        assert_float_eq!(Angle32::bisector_of(a, b).to_degrees(), 30.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::bisector_of(b, a).to_degrees(), 30.0, abs <= 1e-4);
+
+        // Obtuse, across the ±180° boundary.
+        let a = Angle32::from_degrees(120.0);
+        let b = Angle32::from_degrees(-100.0);
+        assert_float_eq!(Angle32::bisector_of(a, b).to_degrees(), -170.0, abs <= 1e-4);
+
+        // Antipodal.
+        let a = Angle32::ZERO;
+        let b = Angle32::DEG_180;
+        assert_float_eq!(Angle32::bisector_of(a, b).to_degrees(), 90.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::bisector_of(b, a).to_degrees(), -90.0, abs <= 1e-4);
+        let a = Angle32::DEG_90;
+        let b = -Angle32::DEG_90;
+        assert_float_eq!(Angle32::bisector_of(a, b).to_degrees(), 180.0, abs <= 1e-4);
+
+        // Antipodal, with a rounded difference.
+        let a = Angle32::from_degrees(10.0);
+        let b = Angle32::from_degrees(-170.0);
+        assert_float_eq!(Angle32::bisector_of(a, b).to_degrees(), 100.0, abs <= 1e-4);
+        assert_float_eq!(Angle32::bisector_of(b, a).to_degrees(), -80.0, abs <= 1e-4);
+        let a = Angle64::from_degrees(10.0);
+        let b = Angle64::from_degrees(-170.0);
+        assert_float_eq!(Angle64::bisector_of(a, b).to_degrees(), 100.0, abs <= 1e-9);
+
+        // Nearly antipodal, on both sides.
+        let a = Angle32::ZERO;
+        let b = Angle32::from_degrees(179.999);
+        assert_float_eq!(
+            Angle32::bisector_of(a, b).to_degrees(),
+            89.9995,
+            abs <= 1e-4
+        );
+        let b = Angle32::from_degrees(-179.999);
+        assert_float_eq!(
+            Angle32::bisector_of(a, b).to_degrees(),
+            -89.9995,
+            abs <= 1e-4
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_dot_perp_dot() {
+        let a = Angle32::from_degrees(20.0);
+        assert_float_eq!(a.dot(a), 1.0, abs <= 1e-6);
+        assert_float_eq!(a.dot(a + Angle32::DEG_90), 0.0, abs <= 1e-6);
+        assert_float_eq!(a.dot(a - Angle32::DEG_90), 0.0, abs <= 1e-6);
+        assert_float_eq!(a.dot(a + Angle32::DEG_180), -1.0, abs <= 1e-6);
+
+        // Counterclockwise is positive, clockwise is negative.
+        assert_float_eq!(a.perp_dot(a + Angle32::DEG_90), 1.0, abs <= 1e-6);
+        assert_float_eq!(a.perp_dot(a - Angle32::DEG_90), -1.0, abs <= 1e-6);
+        assert!(a.perp_dot(a + Angle32::DEG_15) > 0.0);
+        assert!(a.perp_dot(a - Angle32::DEG_15) < 0.0);
+
+        // Across the ±180° boundary.
+        let a = Angle32::from_degrees(170.0);
+        let b = Angle32::from_degrees(-170.0);
+        assert!(a.perp_dot(b) > 0.0);
+        assert!(b.perp_dot(a) < 0.0);
+        assert_float_eq!(a.dot(b), Angle32::from_degrees(20.0).cos(), abs <= 1e-6);
+    }
 }
@@ -0,0 +1,278 @@
+//! Approximate comparisons with the [approx crate](https://docs.rs/approx/latest/approx/).
+//!
+//! [`Angle`] is compared by the shortest difference between the two angles, so angles
+//! on both sides of the ±π seam of [the main range](crate#the-main-range) are close to each other.
+//!
+//! [`AngleUnbounded`] is compared by the difference of its radian values.
+//!
+//! [`RelativeEq`] and [`UlpsEq`] compare the radian value of an [`Angle`] with the radian value of
+//! the other angle that is the closest to it, which may be outside of the main range.
+//! For instance, `-179.9999°` is compared as `180.0001°` against `179.9999°`.
+//!
+//! ```
+//! # use angulus::Angle32;
+//! # use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+//! let a = Angle32::from_degrees(179.9999);
+//! let b = Angle32::from_degrees(-179.9999);
+//!
+//! assert_abs_diff_eq!(a, b, epsilon = 1e-5);
+//! assert_abs_diff_ne!(a, Angle32::ZERO);
+//! ```
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::float::Float;
+use crate::{Angle, AngleUnbounded};
+
+//-------------------------------------------------------------------
+// Angle
+//-------------------------------------------------------------------
+
+impl<F> AbsDiffEq for Angle<F>
+where
+    F: Float + AbsDiffEq<Epsilon = F>,
+{
+    type Epsilon = F;
+
+    /// The default epsilon is [`Angle::EPSILON`], in radians.
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        Self::EPSILON.to_radians()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.angle_to(*other)
+            .to_radians()
+            .abs_diff_eq(&F::ZERO, epsilon)
+    }
+}
+
+impl<F> RelativeEq for Angle<F>
+where
+    F: Float + RelativeEq<Epsilon = F>,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        F::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.to_radians()
+            .relative_eq(&nearest_radians(*self, *other), epsilon, max_relative)
+    }
+}
+
+impl<F> UlpsEq for Angle<F>
+where
+    F: Float + UlpsEq<Epsilon = F>,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        F::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.to_radians()
+            .ulps_eq(&nearest_radians(*self, *other), epsilon, max_ulps)
+    }
+}
+
+/// Returns the radian value of `other` that is the closest to `angle`.
+///
+/// The value is outside of the main range when `angle` and `other` are on both sides of the ±π seam.
+#[inline]
+fn nearest_radians<F: Float>(angle: Angle<F>, other: Angle<F>) -> F {
+    angle.to_radians() + angle.angle_to(other).to_radians()
+}
+
+//-------------------------------------------------------------------
+// AngleUnbounded
+//-------------------------------------------------------------------
+
+impl<F> AbsDiffEq for AngleUnbounded<F>
+where
+    F: Float + AbsDiffEq<Epsilon = F>,
+{
+    type Epsilon = F;
+
+    /// The default epsilon is [`AngleUnbounded::EPSILON`], in radians.
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        Self::EPSILON.to_radians()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.to_radians().abs_diff_eq(&other.to_radians(), epsilon)
+    }
+}
+
+impl<F> RelativeEq for AngleUnbounded<F>
+where
+    F: Float + RelativeEq<Epsilon = F>,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        F::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.to_radians()
+            .relative_eq(&other.to_radians(), epsilon, max_relative)
+    }
+}
+
+impl<F> UlpsEq for AngleUnbounded<F>
+where
+    F: Float + UlpsEq<Epsilon = F>,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        F::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.to_radians()
+            .ulps_eq(&other.to_radians(), epsilon, max_ulps)
+    }
+}
+
+//-------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::PI;
+
+    use approx::{
+        assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_relative_ne,
+        assert_ulps_eq, assert_ulps_ne,
+    };
+
+    use crate::{Angle32, Angle64, AngleUnbounded32};
+
+    #[test]
+    fn angle_abs_diff_eq_across_the_seam() {
+        assert_abs_diff_eq!(Angle32::from_radians(PI), Angle32::from_radians(-PI));
+        assert_abs_diff_eq!(
+            Angle32::from_radians(PI - 1e-6),
+            Angle32::from_radians(-PI + 1e-6),
+            epsilon = 1e-5
+        );
+        assert_abs_diff_eq!(
+            Angle64::from_degrees(179.999_999),
+            Angle64::from_degrees(-179.999_999),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_ne!(
+            Angle32::from_radians(PI - 0.1),
+            Angle32::from_radians(-PI + 0.1),
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn angle_abs_diff_eq() {
+        assert_abs_diff_eq!(
+            Angle32::DEG_90,
+            Angle32::from_degrees(450.0),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(Angle32::DEG_90, Angle32::DEG_90 + Angle32::EPSILON);
+        assert_abs_diff_ne!(Angle32::DEG_90, Angle32::DEG_60);
+        assert_abs_diff_ne!(Angle32::ZERO, Angle32::DEG_180);
+    }
+
+    #[test]
+    fn angle_unbounded_abs_diff_eq() {
+        assert_abs_diff_eq!(
+            AngleUnbounded32::from_degrees(90.0),
+            AngleUnbounded32::from_turns(0.25)
+        );
+        assert_abs_diff_ne!(
+            AngleUnbounded32::from_radians(PI),
+            AngleUnbounded32::from_radians(-PI)
+        );
+        assert_abs_diff_ne!(
+            AngleUnbounded32::from_degrees(90.0),
+            AngleUnbounded32::from_degrees(450.0)
+        );
+    }
+
+    #[test]
+    fn angle_relative_eq_across_the_seam() {
+        let a = Angle32::from_radians(PI - 1e-6);
+        let b = Angle32::from_radians(-PI + 1e-6);
+
+        assert_relative_eq!(a, b, max_relative = 1e-6);
+        assert_relative_eq!(b, a, max_relative = 1e-6);
+        assert_relative_ne!(a, b, epsilon = 0.0, max_relative = 1e-8);
+
+        assert_relative_eq!(Angle32::from_radians(PI), Angle32::from_radians(-PI));
+        assert_relative_ne!(
+            Angle32::from_radians(PI - 0.1),
+            Angle32::from_radians(-PI + 0.1)
+        );
+    }
+
+    #[test]
+    fn angle_relative_eq_near_zero() {
+        assert_relative_eq!(Angle32::ZERO, Angle32::from_radians(-0.0));
+        assert_relative_eq!(Angle32::from_radians(1e-9), Angle32::from_radians(-1e-9));
+        assert_relative_ne!(Angle32::from_radians(1e-3), Angle32::from_radians(-1e-3));
+        assert_relative_ne!(
+            Angle64::from_radians(1e-9),
+            Angle64::from_radians(-1e-9),
+            epsilon = 0.0
+        );
+    }
+
+    #[test]
+    fn angle_ulps_eq() {
+        let a = Angle32::from_radians(PI);
+        let b = Angle32::from_radians(-PI);
+        assert_ulps_eq!(a, b);
+
+        let a = Angle64::from_radians(core::f64::consts::PI - 1e-12);
+        let b = Angle64::from_radians(-core::f64::consts::PI + 1e-12);
+        assert_ulps_eq!(a, b, epsilon = 0.0, max_ulps = 10_000);
+        assert_ulps_ne!(a, b, epsilon = 0.0, max_ulps = 4);
+
+        assert_ulps_eq!(Angle32::ZERO, Angle32::from_radians(1e-9));
+        assert_ulps_ne!(Angle32::ZERO, Angle32::from_radians(1e-3));
+    }
+
+    #[test]
+    fn angle_unbounded_relative_and_ulps_eq() {
+        assert_relative_eq!(
+            AngleUnbounded32::from_degrees(90.0),
+            AngleUnbounded32::from_turns(0.25)
+        );
+        assert_relative_ne!(
+            AngleUnbounded32::from_radians(PI),
+            AngleUnbounded32::from_radians(-PI)
+        );
+        assert_ulps_eq!(
+            AngleUnbounded32::from_degrees(90.0),
+            AngleUnbounded32::from_turns(0.25)
+        );
+        assert_ulps_ne!(
+            AngleUnbounded32::from_degrees(90.0),
+            AngleUnbounded32::from_degrees(450.0)
+        );
+    }
+}
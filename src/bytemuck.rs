@@ -0,0 +1,83 @@
+//! Casting with the [bytemuck crate](https://docs.rs/bytemuck/latest/bytemuck/).
+//!
+//! [`Angle`] and [`AngleUnbounded`] are transparent wrappers around their radian value,
+//! so slices of angles can be cast into slices of floating point numbers (e.g. to upload them to the GPU).
+//!
+//! ```
+//! # use angulus::Angle32;
+//! let angles = [Angle32::ZERO, Angle32::DEG_90, Angle32::DEG_180];
+//! let radians: &[f32] = bytemuck::cast_slice(&angles);
+//!
+//! assert_eq!(radians.len(), 3);
+//! ```
+//!
+//! Casting floating point numbers into [`Angle`]s does not wrap them into
+//! [the main range](crate#the-main-range), like [`Angle::from_radians_unchecked`].
+
+#![allow(unsafe_code)]
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::float::Float;
+use crate::{Angle, AngleUnbounded};
+
+// SAFETY: `Angle` is `repr(transparent)` over `F`, and the zero value is the angle zero.
+unsafe impl<F: Float + Zeroable> Zeroable for Angle<F> {}
+
+// SAFETY: `Angle` is `repr(transparent)` over `F`. Any bit pattern of `F` is a valid
+// (but maybe non-normalized) angle, as with `Angle::from_radians_unchecked`.
+unsafe impl<F: Float + Pod> Pod for Angle<F> {}
+
+// SAFETY: `AngleUnbounded` is `repr(transparent)` over `F`, and the zero value is the angle zero.
+unsafe impl<F: Float + Zeroable> Zeroable for AngleUnbounded<F> {}
+
+// SAFETY: `AngleUnbounded` is `repr(transparent)` over `F`, and any value of `F` is a valid unbounded angle.
+unsafe impl<F: Float + Pod> Pod for AngleUnbounded<F> {}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use crate::{Angle32, Angle64, AngleUnbounded32, AngleUnbounded64};
+
+    #[test]
+    fn angle_cast_slice_roundtrip() {
+        let angles = [
+            Angle32::ZERO,
+            Angle32::DEG_90,
+            -Angle32::DEG_45,
+            Angle32::DEG_180,
+        ];
+
+        let radians: &[f32] = bytemuck::cast_slice(&angles);
+        assert_eq!(radians.len(), angles.len());
+        for (angle, radians) in angles.iter().zip(radians) {
+            assert_float_eq!(angle.to_radians(), *radians, ulps <= 0);
+        }
+
+        let back: &[Angle32] = bytemuck::cast_slice(radians);
+        assert_eq!(back, angles);
+
+        let angles = [Angle64::DEG_60, Angle64::DEG_30];
+        let radians: &[f64] = bytemuck::cast_slice(&angles);
+        let back: &[Angle64] = bytemuck::cast_slice(radians);
+        assert_eq!(back, angles);
+    }
+
+    #[test]
+    fn angle_unbounded_cast_slice_roundtrip() {
+        let angles = [
+            AngleUnbounded32::from_turns(1.5),
+            AngleUnbounded32::from_degrees(-720.0),
+        ];
+        let radians: &[f32] = bytemuck::cast_slice(&angles);
+        let back: &[AngleUnbounded32] = bytemuck::cast_slice(radians);
+        assert_eq!(back, angles);
+
+        let zeroed: AngleUnbounded64 = bytemuck::Zeroable::zeroed();
+        assert_eq!(zeroed, AngleUnbounded64::ZERO);
+
+        let zeroed: Angle32 = bytemuck::Zeroable::zeroed();
+        assert_eq!(zeroed, Angle32::ZERO);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::float::Float;
+use crate::Angle;
+
+/// A direction of the compass rose.
+///
+/// # Convention
+///
+/// The directions follow the same convention as the angles: [`Cardinal::East`] is the
+/// angle zero (the positive x-axis) and angles increase counterclockwise, so
+/// [`Cardinal::North`] is the angle of 90°.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cardinal {
+    /// The north direction (90°).
+    North,
+    /// The north-east direction (45°).
+    NorthEast,
+    /// The east direction (0°).
+    East,
+    /// The south-east direction (-45°).
+    SouthEast,
+    /// The south direction (-90°).
+    South,
+    /// The south-west direction (-135°).
+    SouthWest,
+    /// The west direction (180°).
+    West,
+    /// The north-west direction (135°).
+    NorthWest,
+}
+
+impl Cardinal {
+    /// Returns the angle pointing in this direction.
+    #[must_use]
+    #[inline]
+    pub fn to_angle<F: Float>(self) -> Angle<F> {
+        let frac_3_pi_4 = F::FRAC_PI_2 + F::FRAC_PI_4;
+        let radians = match self {
+            Self::North => F::FRAC_PI_2,
+            Self::NorthEast => F::FRAC_PI_4,
+            Self::East => F::ZERO,
+            Self::SouthEast => -F::FRAC_PI_4,
+            Self::South => -F::FRAC_PI_2,
+            Self::SouthWest => -frac_3_pi_4,
+            Self::West => F::PI,
+            Self::NorthWest => frac_3_pi_4,
+        };
+        Angle::from_radians_unchecked(radians)
+    }
+}
+
+impl<F: Float> From<Cardinal> for Angle<F> {
+    #[inline]
+    fn from(cardinal: Cardinal) -> Self {
+        cardinal.to_angle()
+    }
+}
+
+impl<F: Float> Angle<F> {
+    /// Returns the nearest of the four cardinal directions
+    /// ([`North`][Cardinal::North], [`East`][Cardinal::East], [`South`][Cardinal::South]
+    /// and [`West`][Cardinal::West]).
+    ///
+    /// See [`Cardinal`] for the convention. An angle exactly half-way between two directions
+    /// resolves to the one farther from [`East`][Cardinal::East], and a `NaN` angle
+    /// resolves to [`East`][Cardinal::East].
+    ///
+    /// ```
+    /// # use angulus::{Angle, Cardinal};
+    /// assert_eq!(Angle::from_degrees(80.0_f32).nearest_cardinal(), Cardinal::North);
+    /// assert_eq!(Angle::from_degrees(-30.0_f32).nearest_cardinal(), Cardinal::East);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn nearest_cardinal(self) -> Cardinal {
+        match (self.to_radians() / F::FRAC_PI_2).round_to_i64() {
+            0 => Cardinal::East,
+            1 => Cardinal::North,
+            -1 => Cardinal::South,
+            _ => Cardinal::West,
+        }
+    }
+
+    /// Returns the nearest of the eight cardinal and intercardinal directions.
+    ///
+    /// See [`Cardinal`] for the convention. An angle exactly half-way between two directions
+    /// resolves to the one farther from [`East`][Cardinal::East], and a `NaN` angle
+    /// resolves to [`East`][Cardinal::East].
+    ///
+    /// ```
+    /// # use angulus::{Angle, Cardinal};
+    /// assert_eq!(Angle::from_degrees(50.0_f32).nearest_eight_wind(), Cardinal::NorthEast);
+    /// assert_eq!(Angle::from_degrees(-100.0_f32).nearest_eight_wind(), Cardinal::South);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn nearest_eight_wind(self) -> Cardinal {
+        match (self.to_radians() / F::FRAC_PI_4).round_to_i64() {
+            0 => Cardinal::East,
+            1 => Cardinal::NorthEast,
+            2 => Cardinal::North,
+            3 => Cardinal::NorthWest,
+            -1 => Cardinal::SouthEast,
+            -2 => Cardinal::South,
+            -3 => Cardinal::SouthWest,
+            _ => Cardinal::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle32, Cardinal};
+
+    const ALL: [Cardinal; 8] = [
+        Cardinal::North,
+        Cardinal::NorthEast,
+        Cardinal::East,
+        Cardinal::SouthEast,
+        Cardinal::South,
+        Cardinal::SouthWest,
+        Cardinal::West,
+        Cardinal::NorthWest,
+    ];
+
+    #[test]
+    fn cardinal_roundtrip() {
+        for cardinal in ALL {
+            assert_eq!(cardinal.to_angle::<f32>().nearest_eight_wind(), cardinal);
+        }
+
+        for cardinal in [
+            Cardinal::North,
+            Cardinal::East,
+            Cardinal::South,
+            Cardinal::West,
+        ] {
+            assert_eq!(cardinal.to_angle::<f32>().nearest_cardinal(), cardinal);
+        }
+    }
+
+    #[test]
+    fn nearest_cardinal_midpoints() {
+        macro_rules! check {
+            ($degrees:expr => $expected:ident) => {
+                assert_eq!(
+                    Angle32::from_degrees($degrees).nearest_cardinal(),
+                    Cardinal::$expected
+                );
+            };
+        }
+
+        check!(44.0 => East);
+        check!(45.0 => North);
+        check!(46.0 => North);
+        check!(134.0 => North);
+        check!(135.0 => West);
+        check!(-44.0 => East);
+        check!(-45.0 => South);
+        check!(-134.0 => South);
+        check!(-135.0 => West);
+        check!(180.0 => West);
+    }
+
+    #[test]
+    fn nearest_eight_wind_midpoints() {
+        macro_rules! check {
+            ($degrees:expr => $expected:ident) => {
+                assert_eq!(
+                    Angle32::from_degrees($degrees).nearest_eight_wind(),
+                    Cardinal::$expected
+                );
+            };
+        }
+
+        check!(22.0 => East);
+        check!(23.0 => NorthEast);
+        check!(67.0 => NorthEast);
+        check!(68.0 => North);
+        check!(112.0 => North);
+        check!(113.0 => NorthWest);
+        check!(157.0 => NorthWest);
+        check!(158.0 => West);
+        check!(-22.0 => East);
+        check!(-23.0 => SouthEast);
+        check!(-68.0 => South);
+        check!(-113.0 => SouthWest);
+        check!(-158.0 => West);
+    }
+}
@@ -0,0 +1,458 @@
+//! Pure Rust implementations of the math functions, used by [`FloatMath`][crate::float::FloatMath]
+//! when neither the `std` nor the `libm` feature is enabled.
+//!
+//! The trigonometric functions use the [CORDIC](https://en.wikipedia.org/wiki/CORDIC) algorithm:
+//! each iteration rotates a vector by `±atan(2^-i)` and adds about one bit of precision.
+//! With [`ITERATIONS`] iterations, the error of the results is below `8 * f64::EPSILON`.
+//! Fewer iterations would be faster but less accurate.
+//!
+//! This error is absolute, so it is large relative to a result close to zero.
+//! The sine of a small angle (below [`SMALL_ANGLE`]) is computed with the Taylor series instead,
+//! with a relative error below `f64::EPSILON`: `sin(0)` is exactly `0`.
+//!
+//! The square root uses Newton's method and the natural logarithm uses a series, as CORDIC
+//! is not a good fit for them in floating point arithmetic.
+//!
+//! All the functions compute in `f64`; the `f32` implementations round the result.
+
+// NOTE: the functions are only used when neither `std` nor `libm` are enabled,
+// but they are still compiled to be tested against the `std` implementation.
+#![cfg_attr(any(feature = "std", feature = "libm"), allow(dead_code))]
+
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LN_2, PI, SQRT_2, TAU};
+
+/// The number of CORDIC iterations, one per bit of the `f64` mantissa.
+const ITERATIONS: usize = 54;
+
+/// Below this angle, [`sin_cos`] uses the Taylor series instead of CORDIC.
+const SMALL_ANGLE: f64 = 0.03125;
+
+/// `atan(2^-i)` for the first iterations.
+///
+/// For the following iterations, `atan(2^-i)` rounds to `2^-i`.
+#[allow(clippy::unreadable_literal)]
+const ATAN_TABLE: [f64; 28] = [
+    FRAC_PI_4,
+    0.4636476090008061,
+    0.24497866312686414,
+    0.12435499454676144,
+    0.06241880999595735,
+    0.031239833430268277,
+    0.015623728620476831,
+    0.007812341060101111,
+    0.0039062301319669718,
+    0.0019531225164788188,
+    0.0009765621895593195,
+    0.0004882812111948983,
+    0.00024414062014936177,
+    0.00012207031189367021,
+    6.103515617420877e-05,
+    3.0517578115526096e-05,
+    1.5258789061315762e-05,
+    7.62939453110197e-06,
+    3.814697265606496e-06,
+    1.907348632810187e-06,
+    9.536743164059608e-07,
+    4.7683715820308884e-07,
+    2.3841857910155797e-07,
+    1.1920928955078068e-07,
+    5.960464477539055e-08,
+    2.9802322387695303e-08,
+    1.4901161193847655e-08,
+    7.450580596923828e-09,
+];
+
+/// The inverse of the gain of the CORDIC rotations, i.e. the product of `1 / sqrt(1 + 2^-2i)`.
+#[allow(clippy::unreadable_literal)]
+const INV_GAIN: f64 = 0.6072529350088814;
+
+/// Returns `atan(2^-i)`, where `pow` is `2^-i`.
+#[inline]
+fn atan_pow2(i: usize, pow: f64) -> f64 {
+    ATAN_TABLE.get(i).copied().unwrap_or(pow)
+}
+
+#[inline]
+fn abs(x: f64) -> f64 {
+    if x < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
+#[inline]
+fn signum(x: f64) -> f64 {
+    if x.is_sign_negative() {
+        -1.0
+    } else {
+        1.0
+    }
+}
+
+//-------------------------------------------------------------------
+// Trigonometry
+//-------------------------------------------------------------------
+
+/// Simultaneously computes the sine and cosine.
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    // NOTE: the angles are already in `(-π, π]`, so this is usually a no-op.
+    let mut z = x % TAU;
+    if z > PI {
+        z -= TAU;
+    } else if z < -PI {
+        z += TAU;
+    }
+
+    // CORDIC converges on `[-π/2, π/2]`, use `sin(π - z) = sin(z)` and `cos(π - z) = -cos(z)`.
+    let (mut z, cos_sign) = if z > FRAC_PI_2 {
+        (PI - z, -1.0)
+    } else if z < -FRAC_PI_2 {
+        (-PI - z, -1.0)
+    } else {
+        (z, 1.0)
+    };
+
+    // NOTE: the error of CORDIC is absolute, so it would dominate small sines:
+    // use the Taylor series, whose truncation error is below `f64::EPSILON` relative.
+    if abs(z) < SMALL_ANGLE {
+        let z2 = z * z;
+        let sin = z * (1.0 + z2 * (-1.0 / 6.0 + z2 * (1.0 / 120.0 - z2 / 5040.0)));
+        let cos = 1.0 + z2 * (-0.5 + z2 * (1.0 / 24.0 + z2 * (-1.0 / 720.0 + z2 / 40320.0)));
+        return (sin, cos_sign * cos);
+    }
+
+    let mut x = INV_GAIN;
+    let mut y = 0.0;
+    let mut pow = 1.0;
+    for i in 0..ITERATIONS {
+        let (dx, dy) = (y * pow, x * pow);
+        if z >= 0.0 {
+            x -= dx;
+            y += dy;
+            z -= atan_pow2(i, pow);
+        } else {
+            x += dx;
+            y -= dy;
+            z += atan_pow2(i, pow);
+        }
+        pow *= 0.5;
+    }
+
+    (y, cos_sign * x)
+}
+
+/// Computes the four quadrant arctangent of `y` and `x`.
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    if y.is_nan() || x.is_nan() {
+        return f64::NAN;
+    }
+
+    if y == 0.0 && x == 0.0 {
+        return match (x.is_sign_negative(), y.is_sign_negative()) {
+            (false, _) => y,
+            (true, false) => PI,
+            (true, true) => -PI,
+        };
+    }
+
+    // Only the direction matters: bring infinite values back to finite ones.
+    let (y, x) = match (y.is_infinite(), x.is_infinite()) {
+        (true, true) => (signum(y), signum(x)),
+        (true, false) => (signum(y), 0.0),
+        (false, true) => (signum(y) * 0.0, signum(x)),
+        (false, false) => (y, x),
+    };
+
+    // CORDIC converges for `x >= 0`, rotate the vector by π otherwise.
+    let (mut x, mut y, offset) = if x < 0.0 {
+        (-x, -y, if y.is_sign_negative() { -PI } else { PI })
+    } else {
+        (x, y, 0.0)
+    };
+
+    // Avoid overflows.
+    let scale = if x > abs(y) { x } else { abs(y) };
+    x /= scale;
+    y /= scale;
+
+    let mut z = 0.0;
+    let mut pow = 1.0;
+    for i in 0..ITERATIONS {
+        let (dx, dy) = (y * pow, x * pow);
+        if y > 0.0 {
+            x += dx;
+            y -= dy;
+            z += atan_pow2(i, pow);
+        } else {
+            x -= dx;
+            y += dy;
+            z -= atan_pow2(i, pow);
+        }
+        pow *= 0.5;
+    }
+
+    offset + z
+}
+
+/// Computes the arcsine.
+pub(crate) fn asin(x: f64) -> f64 {
+    atan2(x, sqrt((1.0 - x) * (1.0 + x)))
+}
+
+/// Computes the arccosine.
+pub(crate) fn acos(x: f64) -> f64 {
+    atan2(sqrt((1.0 - x) * (1.0 + x)), x)
+}
+
+/// Computes the arctangent.
+pub(crate) fn atan(x: f64) -> f64 {
+    atan2(x, 1.0)
+}
+
+//-------------------------------------------------------------------
+// Others
+//-------------------------------------------------------------------
+
+/// Computes the square root.
+pub(crate) fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 || x.is_infinite() {
+        return x;
+    }
+
+    // Halve the exponent for a first guess, the first Newton step makes it an upper bound.
+    let guess = f64::from_bits((x.to_bits() >> 1) + (0x3FF << 51));
+    let mut root = 0.5 * (guess + x / guess);
+    loop {
+        let next = 0.5 * (root + x / root);
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// Computes the natural logarithm.
+#[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+pub(crate) fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+
+    // Bring subnormal numbers back to normal ones.
+    let (x, exponent) = if x < f64::MIN_POSITIVE {
+        (x * 18_014_398_509_481_984.0, -54) // 2^54
+    } else {
+        (x, 0)
+    };
+
+    // `x = mantissa * 2^exponent` with `mantissa` in `[1, 2)`.
+    let bits = x.to_bits();
+    let exponent = exponent + ((bits >> 52) & 0x7FF) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000F_FFFF_FFFF_FFFF) | (0x3FF << 52));
+
+    // Center the mantissa on 1 for a faster convergence.
+    let (mantissa, exponent) = if mantissa > SQRT_2 {
+        (mantissa * 0.5, exponent + 1)
+    } else {
+        (mantissa, exponent)
+    };
+
+    // `ln(m) = 2 atanh(s) = 2 (s + s^3/3 + s^5/5 + ...)` with `s = (m - 1) / (m + 1)`, `|s| < 0.18`.
+    let s = (mantissa - 1.0) / (mantissa + 1.0);
+    let s2 = s * s;
+    let mut term = s;
+    let mut sum = 0.0;
+    for k in 0..12 {
+        sum += term / f64::from(2 * k + 1);
+        term *= s2;
+    }
+
+    2.0 * sum + exponent as f64 * LN_2
+}
+
+/// Computes `x * y + z` with the error of the product compensated.
+///
+/// The product is split into its rounded value and its exact error (Dekker's algorithm),
+/// so the result is as accurate as a fused multiply-add except in rare half-way cases
+/// and when the operands are so large that the splitting overflows.
+pub(crate) fn mul_add(x: f64, y: f64, z: f64) -> f64 {
+    /// `2^27 + 1`, splits a `f64` into two halves of 26 bits.
+    const SPLITTER: f64 = 134_217_729.0;
+
+    #[inline]
+    fn split(a: f64) -> (f64, f64) {
+        let c = SPLITTER * a;
+        let high = c - (c - a);
+        (high, a - high)
+    }
+
+    let product = x * y;
+    if !product.is_finite() || !z.is_finite() {
+        return product + z;
+    }
+
+    let (x_high, x_low) = split(x);
+    let (y_high, y_low) = split(y);
+    let product_error =
+        ((x_high * y_high - product) + x_high * y_low + x_low * y_high) + x_low * y_low;
+
+    // Exact sum of `product` and `z` (Knuth's two-sum).
+    let sum = product + z;
+    let v = sum - product;
+    let sum_error = (product - (sum - v)) + (z - v);
+
+    sum + (sum_error + product_error)
+}
+
+//-------------------------------------------------------------------
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    #[test]
+    fn cordic_sin_cos() {
+        for k in -1000..=1000 {
+            let x = f64::from(k) * 0.01;
+            let (sin, cos) = super::sin_cos(x);
+            assert_float_eq!(sin, x.sin(), abs <= 8.0 * f64::EPSILON);
+            assert_float_eq!(cos, x.cos(), abs <= 8.0 * f64::EPSILON);
+        }
+
+        assert!(super::sin_cos(f64::INFINITY).0.is_nan());
+        assert!(super::sin_cos(f64::NAN).1.is_nan());
+    }
+
+    #[test]
+    fn cordic_sin_cos_small_angles() {
+        let (sin, cos) = super::sin_cos(0.0);
+        assert_float_eq!(sin, 0.0, ulps <= 0);
+        assert_float_eq!(cos, 1.0, ulps <= 0);
+        assert!(super::sin_cos(-0.0).0.is_sign_negative());
+
+        for x in [1e-300, 1e-100, 1e-10, -1e-10, 1e-5, 1e-3, -0.02, 0.03] {
+            let (sin, cos) = super::sin_cos(x);
+            assert_float_eq!(sin, x.sin(), r2nd <= f64::EPSILON);
+            assert_float_eq!(cos, x.cos(), ulps <= 1);
+        }
+    }
+
+    #[test]
+    fn cordic_atan2() {
+        for i in -20..=20 {
+            for j in -20..=20 {
+                let (y, x) = (f64::from(i) * 0.37, f64::from(j) * 0.41);
+                assert_float_eq!(super::atan2(y, x), y.atan2(x), abs <= 1e-15);
+            }
+        }
+
+        for (y, x) in [
+            (0.0, -1.0),
+            (-0.0, -1.0),
+            (0.0, 0.0),
+            (-0.0, -0.0),
+            (1e300, 1e-300),
+            (f64::INFINITY, 1.0),
+            (1.0, f64::NEG_INFINITY),
+            (-1.0, f64::NEG_INFINITY),
+            (f64::INFINITY, f64::NEG_INFINITY),
+        ] {
+            assert_float_eq!(super::atan2(y, x), y.atan2(x), abs <= 1e-15);
+        }
+
+        assert!(super::atan2(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn cordic_inverse_trigonometry() {
+        for k in -100..=100 {
+            let x = f64::from(k) * 0.01;
+            assert_float_eq!(super::asin(x), x.asin(), abs <= 4e-15);
+            assert_float_eq!(super::acos(x), x.acos(), abs <= 4e-15);
+            assert_float_eq!(super::atan(x * 10.0), (x * 10.0).atan(), abs <= 1e-15);
+        }
+
+        assert!(super::asin(1.5).is_nan());
+        assert!(super::acos(-1.5).is_nan());
+        assert_float_eq!(
+            super::atan(f64::INFINITY),
+            core::f64::consts::FRAC_PI_2,
+            abs <= 1e-15
+        );
+    }
+
+    #[test]
+    fn cordic_sqrt() {
+        for x in [
+            0.25,
+            1.0,
+            2.0,
+            10.0,
+            12345.678,
+            1e-300,
+            1e300,
+            5e-324,
+            f64::MAX,
+        ] {
+            assert_float_eq!(super::sqrt(x), x.sqrt(), ulps <= 1);
+        }
+
+        assert_float_eq!(super::sqrt(0.0), 0.0, ulps <= 0);
+        assert!(super::sqrt(-1.0).is_nan());
+        assert!(super::sqrt(f64::INFINITY).is_infinite());
+    }
+
+    #[test]
+    fn cordic_mul_add() {
+        for (x, y, z) in [
+            (0.1, 10.0, -1.0),
+            (1.0 + f64::EPSILON, 1.0 - f64::EPSILON, -1.0),
+            (3.0, 1.0 / 3.0, -1.0),
+            (core::f64::consts::PI, 1e10, 2.5),
+            (-7.25, 0.5, 1e-20),
+            (1e200, 1e-200, 0.0),
+        ] {
+            assert_float_eq!(super::mul_add(x, y, z), x.mul_add(y, z), ulps <= 0);
+        }
+
+        assert!(super::mul_add(f64::INFINITY, 0.0, 1.0).is_nan());
+        assert!(super::mul_add(f64::INFINITY, 1.0, 1.0).is_infinite());
+        assert!(super::mul_add(1.0, 1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn cordic_ln() {
+        for x in [
+            0.1,
+            0.5,
+            1.0,
+            1.5,
+            2.0,
+            core::f64::consts::E,
+            10.0,
+            1e-300,
+            1e300,
+            5e-324,
+        ] {
+            assert_float_eq!(super::ln(x), x.ln(), r2nd <= 1e-15);
+        }
+
+        assert_float_eq!(super::ln(1.0), 0.0, abs <= 1e-16);
+        assert!(super::ln(-1.0).is_nan());
+        assert!(super::ln(0.0).is_infinite());
+    }
+}
@@ -0,0 +1,16 @@
+use core::fmt::Display;
+
+/// An error returned when creating an angle from a value that is infinite or `NaN`.
+///
+/// See [`Angle::try_from_radians`][crate::Angle::try_from_radians].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonFiniteError;
+
+impl Display for NonFiniteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("angle value is not finite")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonFiniteError {}
@@ -33,6 +33,8 @@ pub trait Float:
     const ZERO: Self;
     /// The multiplicative identity element of `Self` (aka `1`).
     const ONE: Self;
+    /// Not a Number (NaN).
+    const NAN: Self;
 
     /// [Machine epsilon] value for `Self`.
     ///
@@ -60,6 +62,12 @@ pub trait Float:
     const FRAC_PI_6: Self;
     /// π/8
     const FRAC_PI_8: Self;
+    /// π/12
+    const FRAC_PI_12: Self;
+    /// π/16
+    const FRAC_PI_16: Self;
+    /// The golden angle, i.e. `τ * (1 - 1/φ)` where `φ` is the golden ratio.
+    const GOLDEN_ANGLE: Self;
 
     /// Conversion factor from degrees to radians.
     const DEG_TO_RAD: Self;
@@ -76,16 +84,121 @@ pub trait Float:
     /// Conversion factor from radians to gradians.
     const RAD_TO_GRAD: Self;
 
+    /// Conversion factor from arcminutes to radians.
+    const ARCMIN_TO_RAD: Self;
+    /// Conversion factor from radians to arcminutes.
+    const RAD_TO_ARCMIN: Self;
+
+    /// Conversion factor from arcseconds to radians.
+    const ARCSEC_TO_RAD: Self;
+    /// Conversion factor from radians to arcseconds.
+    const RAD_TO_ARCSEC: Self;
+
+    /// Conversion factor from NATO mils to radians.
+    const MIL_TO_RAD: Self;
+    /// Conversion factor from radians to NATO mils.
+    const RAD_TO_MIL: Self;
+
+    /// Conversion factor from hours to radians.
+    const HOUR_TO_RAD: Self;
+    /// Conversion factor from radians to hours.
+    const RAD_TO_HOUR: Self;
+
     /// Returns `true` if this value is NaN.
     #[must_use]
     fn is_nan(self) -> bool;
+
+    /// Returns `true` if this value is neither infinite nor NaN.
+    #[must_use]
+    fn is_finite(self) -> bool;
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0`, `NaN`s with positive
+    /// sign bit and positive infinity.
+    #[must_use]
+    fn is_sign_positive(self) -> bool;
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0`, `NaN`s with negative
+    /// sign bit and negative infinity.
+    #[must_use]
+    fn is_sign_negative(self) -> bool;
+
+    /// Computes the absolute value of `self`.
+    #[must_use]
+    fn abs(self) -> Self;
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`.
+    #[must_use]
+    fn copysign(self, sign: Self) -> Self;
+
+    /// Returns `1.0` if `self` has a positive sign (including `+0.0` and `+∞`),
+    /// `-1.0` if it has a negative sign (including `-0.0` and `-∞`) and `NaN` if it is `NaN`.
+    #[must_use]
+    fn signum(self) -> Self;
+
+    /// Converts an [`i64`] into `Self`, rounding to the nearest representable value.
+    #[must_use]
+    fn from_i64(x: i64) -> Self;
+
+    /// Converts an [`u64`] into `Self`, rounding to the nearest representable value.
+    #[must_use]
+    fn from_u64(x: u64) -> Self;
+
+    /// Rounds this value to the nearest [`i64`], rounding half-way cases away from zero.
+    ///
+    /// Out of range values saturate to [`i64::MIN`] or [`i64::MAX`], `NaN` returns `0`.
+    #[must_use]
+    fn round_to_i64(self) -> i64;
+
+    /// Truncates this value toward zero into an [`i64`].
+    ///
+    /// Out of range values saturate to [`i64::MIN`] or [`i64::MAX`], `NaN` returns `0`.
+    #[must_use]
+    fn trunc_to_i64(self) -> i64;
+}
+
+/// Conversion between the [`Float`] types.
+///
+/// This trait is sealed and is implemented for every pair of [`f32`] and [`f64`].
+pub trait FloatCast<T: Float>: Float {
+    /// Converts this value into `T`, rounding to the nearest representable value.
+    #[must_use]
+    fn cast(self) -> T;
+}
+
+impl FloatCast<f32> for f32 {
+    #[inline]
+    fn cast(self) -> f32 {
+        self
+    }
+}
+
+impl FloatCast<f64> for f32 {
+    #[inline]
+    fn cast(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl FloatCast<f32> for f64 {
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn cast(self) -> f32 {
+        self as f32
+    }
+}
+
+impl FloatCast<f64> for f64 {
+    #[inline]
+    fn cast(self) -> f64 {
+        self
+    }
 }
 
 /// Maths operations for the [`Float`] types.
 ///
-/// Require either the `std` or the `libm` feature flag.
-#[cfg(any(feature = "std", feature = "libm"))]
-pub trait FloatMath: private::Sealed + Sized {
+/// Require either the `std`, the `libm` or the `cordic` feature flag.
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+pub trait FloatMath: Float {
     /// Computes the sine (in radians).
     #[must_use = "method returns a new number and does not mutate the original value"]
     fn sin(self) -> Self;
@@ -98,6 +211,27 @@ pub trait FloatMath: private::Sealed + Sized {
     /// Simultaneously computes the sine and cosine. Returns `(sin(x), cos(x))`.
     #[must_use = "method returns a new number and does not mutate the original value"]
     fn sin_cos(self) -> (Self, Self);
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn atan2(self, other: Self) -> Self;
+    /// Computes the arcsine (in radians).
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn asin(self) -> Self;
+    /// Computes the arccosine (in radians).
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn acos(self) -> Self;
+    /// Computes the arctangent (in radians).
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn atan(self) -> Self;
+    /// Computes the square root.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn sqrt(self) -> Self;
+    /// Computes the natural logarithm.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn ln(self) -> Self;
+    /// Computes `(self * a) + b` with only one rounding error.
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    fn mul_add(self, a: Self, b: Self) -> Self;
 }
 
 //-------------------------------------------------------------------
@@ -107,6 +241,7 @@ pub trait FloatMath: private::Sealed + Sized {
 impl Float for f32 {
     const ZERO: Self = 0.0f32;
     const ONE: Self = 1.0f32;
+    const NAN: Self = f32::NAN;
     const EPSILON: Self = f32::EPSILON;
     const DOUBLE_EPSILON: Self = 2.0 * Self::EPSILON;
 
@@ -117,6 +252,9 @@ impl Float for f32 {
     const FRAC_PI_4: Self = core::f32::consts::FRAC_PI_4;
     const FRAC_PI_6: Self = core::f32::consts::FRAC_PI_6;
     const FRAC_PI_8: Self = core::f32::consts::FRAC_PI_8;
+    const FRAC_PI_12: Self = core::f32::consts::PI / 12.0;
+    const FRAC_PI_16: Self = core::f32::consts::PI / 16.0;
+    const GOLDEN_ANGLE: Self = 2.399_963_2;
 
     const DEG_TO_RAD: Self = core::f32::consts::PI / 180.0;
     const RAD_TO_DEG: Self = 180.0 / core::f32::consts::PI;
@@ -127,10 +265,91 @@ impl Float for f32 {
     const GRAD_TO_RAD: Self = core::f32::consts::PI / 200.0;
     const RAD_TO_GRAD: Self = 200.0 / core::f32::consts::PI;
 
+    const ARCMIN_TO_RAD: Self = core::f32::consts::PI / 10_800.0;
+    const RAD_TO_ARCMIN: Self = 10_800.0 / core::f32::consts::PI;
+
+    const ARCSEC_TO_RAD: Self = core::f32::consts::PI / 648_000.0;
+    const RAD_TO_ARCSEC: Self = 648_000.0 / core::f32::consts::PI;
+
+    const MIL_TO_RAD: Self = core::f32::consts::TAU / 6400.0;
+    const RAD_TO_MIL: Self = 6400.0 / core::f32::consts::TAU;
+
+    const HOUR_TO_RAD: Self = core::f32::consts::PI / 12.0;
+    const RAD_TO_HOUR: Self = 12.0 / core::f32::consts::PI;
+
     #[inline]
     fn is_nan(self) -> bool {
         self.is_nan()
     }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.is_sign_negative()
+    }
+
+    // NOTE: `abs` and `signum` are not available in `core`, so they are implemented on the bits.
+
+    #[inline]
+    fn abs(self) -> Self {
+        Self::from_bits(self.to_bits() & !(1 << 31))
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        Self::from_bits((self.to_bits() & !(1 << 31)) | (sign.to_bits() & (1 << 31)))
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        if self.is_nan() {
+            Self::NAN
+        } else {
+            Self::from_bits(1.0f32.to_bits() | (self.to_bits() & (1 << 31)))
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    fn from_i64(x: i64) -> Self {
+        x as f32
+    }
+
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    fn from_u64(x: u64) -> Self {
+        x as f32
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn round_to_i64(self) -> i64 {
+        // NOTE: `as` casts saturate and map `NaN` to `0`.
+        let trunc = self as i64;
+        let fract = self - trunc as f32;
+        if fract >= 0.5 {
+            trunc.saturating_add(1)
+        } else if fract <= -0.5 {
+            trunc.saturating_sub(1)
+        } else {
+            trunc
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn trunc_to_i64(self) -> i64 {
+        self as i64
+    }
 }
 
 #[cfg(feature = "std")]
@@ -154,6 +373,41 @@ impl FloatMath for f32 {
     fn sin_cos(self) -> (Self, Self) {
         self.sin_cos()
     }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        self.atan2(other)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        self.asin()
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        self.acos()
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        self.atan()
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.ln()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add(a, b)
+    }
 }
 
 #[cfg(all(not(feature = "std"), feature = "libm"))]
@@ -177,6 +431,102 @@ impl FloatMath for f32 {
     fn sin_cos(self) -> (Self, Self) {
         (libm::sinf(self), libm::cosf(self))
     }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2f(self, other)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        libm::asinf(self)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        libm::acosf(self)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        libm::atanf(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        libm::logf(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        libm::fmaf(self, a, b)
+    }
+}
+
+#[cfg(all(not(feature = "std"), not(feature = "libm"), feature = "cordic"))]
+#[allow(clippy::cast_possible_truncation)]
+impl FloatMath for f32 {
+    #[inline]
+    fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        sin / cos
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = crate::cordic::sin_cos(f64::from(self));
+        (sin as f32, cos as f32)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        crate::cordic::atan2(f64::from(self), f64::from(other)) as f32
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        crate::cordic::asin(f64::from(self)) as f32
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        crate::cordic::acos(f64::from(self)) as f32
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        crate::cordic::atan(f64::from(self)) as f32
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        crate::cordic::sqrt(f64::from(self)) as f32
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        crate::cordic::ln(f64::from(self)) as f32
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        crate::cordic::mul_add(f64::from(self), f64::from(a), f64::from(b)) as f32
+    }
 }
 
 //-------------------------------------------------------------------
@@ -186,6 +536,7 @@ impl FloatMath for f32 {
 impl Float for f64 {
     const ZERO: Self = 0.0f64;
     const ONE: Self = 1.0f64;
+    const NAN: Self = f64::NAN;
     const EPSILON: Self = f64::EPSILON;
     const DOUBLE_EPSILON: Self = 2.0 * Self::EPSILON;
 
@@ -196,6 +547,9 @@ impl Float for f64 {
     const FRAC_PI_4: Self = core::f64::consts::FRAC_PI_4;
     const FRAC_PI_6: Self = core::f64::consts::FRAC_PI_6;
     const FRAC_PI_8: Self = core::f64::consts::FRAC_PI_8;
+    const FRAC_PI_12: Self = core::f64::consts::PI / 12.0;
+    const FRAC_PI_16: Self = core::f64::consts::PI / 16.0;
+    const GOLDEN_ANGLE: Self = 2.399_963_229_728_653;
 
     const DEG_TO_RAD: Self = core::f64::consts::PI / 180.0;
     const RAD_TO_DEG: Self = 180.0 / core::f64::consts::PI;
@@ -206,10 +560,91 @@ impl Float for f64 {
     const GRAD_TO_RAD: Self = core::f64::consts::PI / 200.0;
     const RAD_TO_GRAD: Self = 200.0 / core::f64::consts::PI;
 
+    const ARCMIN_TO_RAD: Self = core::f64::consts::PI / 10_800.0;
+    const RAD_TO_ARCMIN: Self = 10_800.0 / core::f64::consts::PI;
+
+    const ARCSEC_TO_RAD: Self = core::f64::consts::PI / 648_000.0;
+    const RAD_TO_ARCSEC: Self = 648_000.0 / core::f64::consts::PI;
+
+    const MIL_TO_RAD: Self = core::f64::consts::TAU / 6400.0;
+    const RAD_TO_MIL: Self = 6400.0 / core::f64::consts::TAU;
+
+    const HOUR_TO_RAD: Self = core::f64::consts::PI / 12.0;
+    const RAD_TO_HOUR: Self = 12.0 / core::f64::consts::PI;
+
     #[inline]
     fn is_nan(self) -> bool {
         self.is_nan()
     }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.is_sign_negative()
+    }
+
+    // NOTE: `abs` and `signum` are not available in `core`, so they are implemented on the bits.
+
+    #[inline]
+    fn abs(self) -> Self {
+        Self::from_bits(self.to_bits() & !(1 << 63))
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        Self::from_bits((self.to_bits() & !(1 << 63)) | (sign.to_bits() & (1 << 63)))
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        if self.is_nan() {
+            Self::NAN
+        } else {
+            Self::from_bits(1.0f64.to_bits() | (self.to_bits() & (1 << 63)))
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    fn from_i64(x: i64) -> Self {
+        x as f64
+    }
+
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    fn from_u64(x: u64) -> Self {
+        x as f64
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn round_to_i64(self) -> i64 {
+        // NOTE: `as` casts saturate and map `NaN` to `0`.
+        let trunc = self as i64;
+        let fract = self - trunc as f64;
+        if fract >= 0.5 {
+            trunc.saturating_add(1)
+        } else if fract <= -0.5 {
+            trunc.saturating_sub(1)
+        } else {
+            trunc
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn trunc_to_i64(self) -> i64 {
+        self as i64
+    }
 }
 
 #[cfg(feature = "std")]
@@ -233,6 +668,41 @@ impl FloatMath for f64 {
     fn sin_cos(self) -> (Self, Self) {
         self.sin_cos()
     }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        self.atan2(other)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        self.asin()
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        self.acos()
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        self.atan()
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.ln()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add(a, b)
+    }
 }
 
 #[cfg(all(not(feature = "std"), feature = "libm"))]
@@ -256,4 +726,146 @@ impl FloatMath for f64 {
     fn sin_cos(self) -> (Self, Self) {
         (libm::sin(self), libm::cos(self))
     }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2(self, other)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        libm::asin(self)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        libm::acos(self)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        libm::atan(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        libm::log(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        libm::fma(self, a, b)
+    }
+}
+
+#[cfg(all(not(feature = "std"), not(feature = "libm"), feature = "cordic"))]
+impl FloatMath for f64 {
+    #[inline]
+    fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        sin / cos
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = crate::cordic::sin_cos(self);
+        (sin, cos)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        crate::cordic::atan2(self, other)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        crate::cordic::asin(self)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        crate::cordic::acos(self)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        crate::cordic::atan(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        crate::cordic::sqrt(self)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        crate::cordic::ln(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        crate::cordic::mul_add(self, a, b)
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm", feature = "cordic")))]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use super::FloatMath;
+
+    #[test]
+    fn float_math_inverse_trigonometry_known_values() {
+        macro_rules! check {
+            ($($F:ident: $tolerance:expr),*) => {
+                $({
+                    use core::$F::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, PI};
+
+                    let check = |actual: $F, expected: $F| {
+                        assert_float_eq!(actual, expected, abs <= $tolerance);
+                    };
+
+                    check(<$F as FloatMath>::asin(0.0), 0.0);
+                    check(<$F as FloatMath>::asin(0.5), FRAC_PI_6);
+                    check(<$F as FloatMath>::asin(1.0), FRAC_PI_2);
+                    check(<$F as FloatMath>::asin(-1.0), -FRAC_PI_2);
+
+                    check(<$F as FloatMath>::acos(1.0), 0.0);
+                    check(<$F as FloatMath>::acos(0.5), FRAC_PI_3);
+                    check(<$F as FloatMath>::acos(0.0), FRAC_PI_2);
+                    check(<$F as FloatMath>::acos(-1.0), PI);
+
+                    check(<$F as FloatMath>::atan(0.0), 0.0);
+                    check(<$F as FloatMath>::atan(1.0), FRAC_PI_4);
+                    check(<$F as FloatMath>::atan(-1.0), -FRAC_PI_4);
+                    check(<$F as FloatMath>::atan(1e20), FRAC_PI_2);
+
+                    check(<$F as FloatMath>::atan2(0.0, 1.0), 0.0);
+                    check(<$F as FloatMath>::atan2(1.0, 1.0), FRAC_PI_4);
+                    check(<$F as FloatMath>::atan2(1.0, 0.0), FRAC_PI_2);
+                    check(<$F as FloatMath>::atan2(1.0, -1.0), 3.0 * FRAC_PI_4);
+                    check(<$F as FloatMath>::atan2(0.0, -1.0), PI);
+                    check(<$F as FloatMath>::atan2(-1.0, -1.0), -3.0 * FRAC_PI_4);
+                    check(<$F as FloatMath>::atan2(-2.0, 0.0), -FRAC_PI_2);
+                })*
+            };
+        }
+
+        check!(f32: 1e-6, f64: 1e-14);
+    }
 }
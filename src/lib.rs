@@ -33,22 +33,57 @@
 //! - `(-180, 180]` degrees
 //! - `(-0.5, 0.5]` turns
 //! - `(-200, 200]` gradians
+//! - `(-10800, 10800]` arcminutes
+//! - `(-648000, 648000]` arcseconds
+//! - `(-3200, 3200]` NATO mils
+//! - `(-12, 12]` hours
 //!
 //! # Display
 //!
 //! Since [`Angle`] and [`AngleUnbounded`] are unit-agnostic, they cannot implement the [`Display`][std::fmt::Display] trait.
 //!
-//! To display an angle with a specific unit, wrap it in one of the unit struct of [the `units` module][units].
+//! To display an angle with a specific unit, wrap it in one of the unit struct of [the `units` module][units],
+//! or use [`Angle::display_as`] to choose the unit at runtime.
 //!
 //! # Crate features
 //!
 //! - `std`: by default angulus links to the standard library. Disable this feature to remove this dependency and be able to use angulus in `#![no_std]` crates.
+//! - `alloc`: enable the methods that allocate (e.g. [`Angle::unwrap_sequence`] and [`Angle::smallest_enclosing_arc`]) without the standard library. Enabled by `std`.
 //! - `libm`: use the [libm crate](https://docs.rs/libm/latest/libm/) for the math methods (sin, cos, tan) when `std` is disabled.
+//! - `cordic`: use a pure Rust implementation based on the [CORDIC](https://en.wikipedia.org/wiki/CORDIC) algorithm for the math methods when both `std` and `libm` are disabled.
 //! - `serde`: enable serialization and deserialization with the [serde crate](https://docs.rs/serde/latest/serde/).
 //! - `rand`: enable generation of random angle with the [rand crate](https://docs.rs/rand/latest/rand/).
+//! - `approx`: enable approximate comparisons with the [approx crate](https://docs.rs/approx/latest/approx/).
+//! - `bytemuck`: enable casting angles from and to floating point numbers with the [bytemuck crate](https://docs.rs/bytemuck/latest/bytemuck/).
+//! - `num-complex`: enable conversion from and to complex numbers of the [num-complex crate](https://docs.rs/num-complex/latest/num_complex/) (requires `std`, `libm` or `cordic`).
+//! - `num-traits`: implement the numeric traits of the [num-traits crate](https://docs.rs/num-traits/latest/num_traits/).
+//! - `nalgebra`: enable conversion from and to the `UnitComplex` rotations of the [nalgebra crate](https://docs.rs/nalgebra/latest/nalgebra/) (requires `std` or `libm`).
+//! - `step_trait`: implement the unstable [`Step`](https://doc.rust-lang.org/nightly/core/iter/trait.Step.html) trait for [`AngleUnbounded`], so it can be used in ranges stepping by one degree (requires a nightly compiler, has no effect otherwise). The step is fixed: use [`Iterator::step_by`] for coarser steps.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(all(feature = "step_trait", angulus_nightly), feature(step_trait))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "approx")]
+pub mod approx;
+
+#[cfg(all(
+    feature = "num-complex",
+    any(feature = "std", feature = "libm", feature = "cordic")
+))]
+pub mod num_complex;
+
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+
+#[cfg(all(feature = "nalgebra", any(feature = "std", feature = "libm")))]
+pub mod nalgebra;
+
+#[cfg(feature = "num-traits")]
+pub mod num_traits;
 
 #[cfg(feature = "serde")]
 pub mod serde;
@@ -57,20 +92,30 @@ pub mod serde;
 pub mod rand;
 
 mod angle;
+mod cardinal;
+#[cfg(feature = "cordic")]
+mod cordic;
+mod error;
 pub mod float;
 mod macros;
+mod parse;
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+pub mod stats;
 mod to_angle;
 mod unbounded;
 pub mod units;
 
 pub use angle::Angle;
+pub use cardinal::Cardinal;
+pub use error::NonFiniteError;
+pub use parse::ParseAngleError;
 pub use to_angle::ToAngle;
 pub use unbounded::AngleUnbounded;
 
 #[doc = include_str!("../README.md")]
 #[cfg(all(
     doctest,
-    any(feature = "std", feature = "libm") // Readme uses math methods.
+    any(feature = "std", feature = "libm", feature = "cordic") // Readme uses math methods.
 ))]
 pub struct ReadmeDoctests;
 
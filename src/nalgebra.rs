@@ -0,0 +1,90 @@
+//! Conversion from and to the rotations of the [nalgebra crate](https://docs.rs/nalgebra/latest/nalgebra/).
+//!
+//! An [`Angle`] converts into the [`UnitComplex`] rotation by that angle, and back.
+//!
+//! ```
+//! # use angulus::Angle32;
+//! # use float_eq::assert_float_eq;
+//! # use nalgebra::{UnitComplex, Vector2};
+//! let rotation = UnitComplex::from(Angle32::DEG_90);
+//! let v = rotation * Vector2::new(1.0, 0.0);
+//! assert_float_eq!(v.x, 0.0, abs <= 1e-6);
+//! assert_float_eq!(v.y, 1.0, abs <= 1e-6);
+//!
+//! let angle = Angle32::from(rotation);
+//! assert_float_eq!(angle.to_degrees(), 90.0, abs <= 1e-4);
+//! ```
+
+use nalgebra::{RealField, UnitComplex};
+
+use crate::float::Float;
+use crate::Angle;
+
+impl<F: Float + RealField> From<Angle<F>> for UnitComplex<F> {
+    #[inline]
+    fn from(angle: Angle<F>) -> Self {
+        UnitComplex::new(angle.to_radians())
+    }
+}
+
+impl<F: Float + RealField> From<UnitComplex<F>> for Angle<F> {
+    #[inline]
+    fn from(rotation: UnitComplex<F>) -> Self {
+        Angle::from_radians(rotation.angle())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use nalgebra::UnitComplex;
+
+    use crate::{Angle32, Angle64};
+
+    #[test]
+    fn angle_unit_complex_roundtrip() {
+        for degrees in [0.0, 30.0, 90.0, 135.0, 180.0, -45.0, -120.0] {
+            let angle = Angle64::from_degrees(degrees);
+            let roundtrip = Angle64::from(UnitComplex::from(angle));
+            assert_float_eq!(
+                angle.angular_distance(roundtrip).to_radians(),
+                0.0,
+                abs <= 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn angle_unit_complex_composition() {
+        let pairs = [
+            (30.0, 60.0),
+            (120.0, 120.0),
+            (170.0, 20.0),
+            (-100.0, -100.0),
+            (45.0, -90.0),
+        ];
+
+        for (a, b) in pairs {
+            let a = Angle32::from_degrees(a);
+            let b = Angle32::from_degrees(b);
+
+            let composed = UnitComplex::from(a) * UnitComplex::from(b);
+            let composed = Angle32::from(composed);
+            assert_float_eq!(
+                composed.angular_distance(a + b).to_radians(),
+                0.0,
+                abs <= 1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn angle_from_unit_complex_is_in_main_range() {
+        use core::f32::consts::PI;
+
+        for radians in [PI, -PI, 3.0 * PI, -2.5 * PI] {
+            let angle = Angle32::from(UnitComplex::new(radians));
+            assert!(-PI < angle.to_radians() && angle.to_radians() <= PI);
+        }
+    }
+}
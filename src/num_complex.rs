@@ -0,0 +1,83 @@
+//! Conversion from and to complex numbers of the [num-complex crate](https://docs.rs/num-complex/latest/num_complex/).
+//!
+//! An angle `θ` is represented by the unit complex number `e^{iθ} = cos θ + i sin θ`.
+//!
+//! ```
+//! # use angulus::Angle32;
+//! # use float_eq::assert_float_eq;
+//! let c = Angle32::DEG_90.to_complex();
+//! assert_float_eq!(c.re, 0.0, abs <= 1e-6);
+//! assert_float_eq!(c.im, 1.0, abs <= 1e-6);
+//!
+//! let angle = Angle32::from_complex(c * 2.0);
+//! assert_float_eq!(angle.to_degrees(), 90.0, abs <= 1e-4);
+//! ```
+
+use num_complex::Complex;
+
+use crate::float::FloatMath;
+use crate::Angle;
+
+impl<F: FloatMath> Angle<F> {
+    /// Returns the unit complex number `e^{iθ}` of the angle, i.e. `cos θ + i sin θ`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_complex(self) -> Complex<F> {
+        let (sin, cos) = self.sin_cos();
+        Complex::new(cos, sin)
+    }
+
+    /// Creates a new angle from the argument of a complex number.
+    ///
+    /// The magnitude of the complex number is ignored. The angle of zero is zero.
+    #[must_use]
+    #[inline]
+    pub fn from_complex(c: Complex<F>) -> Self {
+        Self::from_atan2(c.im, c.re)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use num_complex::Complex;
+
+    use crate::{Angle32, Angle64};
+
+    #[test]
+    fn angle_to_complex() {
+        let c = Angle32::ZERO.to_complex();
+        assert_float_eq!(c.re, 1.0, abs <= 1e-6);
+        assert_float_eq!(c.im, 0.0, abs <= 1e-6);
+
+        let c = Angle32::DEG_180.to_complex();
+        assert_float_eq!(c.re, -1.0, abs <= 1e-6);
+        assert_float_eq!(c.im, 0.0, abs <= 1e-6);
+
+        let c = (-Angle64::DEG_45).to_complex();
+        assert_float_eq!(c.re, core::f64::consts::FRAC_1_SQRT_2, abs <= 1e-12);
+        assert_float_eq!(c.im, -core::f64::consts::FRAC_1_SQRT_2, abs <= 1e-12);
+    }
+
+    #[test]
+    fn angle_complex_roundtrip() {
+        for degrees in [0.0, 30.0, 90.0, 135.0, 180.0, -45.0, -120.0] {
+            let angle = Angle32::from_degrees(degrees);
+            let roundtrip = Angle32::from_complex(angle.to_complex());
+            assert_float_eq!(
+                angle.angular_distance(roundtrip).to_radians(),
+                0.0,
+                abs <= 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn angle_from_complex_ignores_magnitude() {
+        let angle = Angle64::from_complex(Complex::new(-3.0, 3.0));
+        assert_float_eq!(angle.to_degrees(), 135.0, abs <= 1e-9);
+
+        let angle = Angle64::from_complex(Complex::new(0.0, 0.0));
+        assert_float_eq!(angle.to_radians(), 0.0, ulps <= 0);
+    }
+}
@@ -0,0 +1,67 @@
+//! Implementation of the numeric traits of the [num-traits crate](https://docs.rs/num-traits/latest/num_traits/).
+//!
+//! ```
+//! # use angulus::Angle32;
+//! # use num_traits::Zero;
+//! fn total<T: Zero + Copy>(values: &[T]) -> T {
+//!     values.iter().fold(T::zero(), |acc, &x| acc + x)
+//! }
+//!
+//! let angle = total(&[Angle32::DEG_30, Angle32::DEG_60]);
+//! assert_eq!(angle, Angle32::DEG_90);
+//! ```
+
+use num_traits::Zero;
+
+use crate::float::Float;
+use crate::{Angle, AngleUnbounded};
+
+impl<F: Float> Zero for Angle<F> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.to_radians() == F::ZERO
+    }
+}
+
+impl<F: Float> Zero for AngleUnbounded<F> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.to_radians() == F::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::Zero;
+
+    use crate::{Angle32, Angle64, AngleUnbounded32, AngleUnbounded64};
+
+    #[test]
+    fn angle_zero() {
+        assert_eq!(Angle32::zero(), Angle32::ZERO);
+        assert_eq!(Angle64::zero(), Angle64::ZERO);
+        assert!(Angle32::zero().is_zero());
+        assert!(Angle32::from_radians(-0.0).is_zero());
+        assert!((Angle32::DEG_90 - Angle32::DEG_90).is_zero());
+        assert!(!Angle32::DEG_90.is_zero());
+        assert!(!Angle32::from_radians(f32::NAN).is_zero());
+    }
+
+    #[test]
+    fn angle_unbounded_zero() {
+        assert_eq!(AngleUnbounded32::zero(), AngleUnbounded32::ZERO);
+        assert_eq!(AngleUnbounded64::zero(), AngleUnbounded64::ZERO);
+        assert!(AngleUnbounded32::zero().is_zero());
+        assert!(!AngleUnbounded32::from_turns(1.0).is_zero());
+    }
+}
@@ -0,0 +1,242 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::float::Float;
+use crate::units::Unit;
+use crate::{Angle, AngleUnbounded};
+
+//-------------------------------------------------------------------
+// Error
+//-------------------------------------------------------------------
+
+/// An error which can be returned when parsing an angle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAngleError {
+    kind: ParseAngleErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParseAngleErrorKind {
+    Empty,
+    InvalidValue,
+}
+
+impl ParseAngleError {
+    const EMPTY: Self = Self {
+        kind: ParseAngleErrorKind::Empty,
+    };
+
+    const INVALID_VALUE: Self = Self {
+        kind: ParseAngleErrorKind::InvalidValue,
+    };
+}
+
+impl Display for ParseAngleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self.kind {
+            ParseAngleErrorKind::Empty => "cannot parse angle from empty string",
+            ParseAngleErrorKind::InvalidValue => "invalid angle value",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAngleError {}
+
+//-------------------------------------------------------------------
+// Parsing
+//-------------------------------------------------------------------
+
+/// The recognized suffixes and their unit.
+///
+/// A suffix that ends with another one must come first (e.g. `deg` before `g`).
+const SUFFIXES: [(&str, Unit); 6] = [
+    ("rad", Unit::Radians),
+    ("deg", Unit::Degrees),
+    ("°", Unit::Degrees),
+    ("tr", Unit::Turns),
+    ("gon", Unit::Gradians),
+    ("g", Unit::Gradians),
+];
+
+/// Parses a number optionally followed by a unit suffix.
+///
+/// A value without suffix is in radians.
+fn parse<F: FromStr>(s: &str) -> Result<(F, Unit), ParseAngleError> {
+    let s = s.trim();
+
+    if s.is_empty() {
+        return Err(ParseAngleError::EMPTY);
+    }
+
+    let (value, unit) = SUFFIXES
+        .iter()
+        .find_map(|&(suffix, unit)| s.strip_suffix(suffix).map(|value| (value, unit)))
+        .unwrap_or((s, Unit::Radians));
+
+    let value = value
+        .trim_end()
+        .parse()
+        .map_err(|_| ParseAngleError::INVALID_VALUE)?;
+
+    Ok((value, unit))
+}
+
+/// Parses a number optionally followed by the symbol of a unit.
+///
+/// The leading whitespaces of `symbol` are ignored, so both `90 rad` and `90rad` are accepted for ` rad`.
+pub(crate) fn parse_with_symbol<F: FromStr>(s: &str, symbol: &str) -> Result<F, ParseAngleError> {
+    let s = s.trim();
+
+    if s.is_empty() {
+        return Err(ParseAngleError::EMPTY);
+    }
+
+    s.strip_suffix(symbol.trim_start())
+        .unwrap_or(s)
+        .trim_end()
+        .parse()
+        .map_err(|_| ParseAngleError::INVALID_VALUE)
+}
+
+/// Parses an angle from a number followed by an optional unit suffix.
+///
+/// The recognized suffixes are `rad`, `deg` (or `°`), `tr` and `g` (or `gon`).
+/// A value without suffix is in radians.
+/// Whitespaces around the value and between the number and the suffix are ignored.
+///
+/// ```
+/// # use angulus::Angle32;
+/// # use float_eq::assert_float_eq;
+/// let angle: Angle32 = "90°".parse().unwrap();
+/// assert_float_eq!(angle.to_degrees(), 90.0, abs <= 0.0001);
+///
+/// let angle: Angle32 = "0.25 tr".parse().unwrap();
+/// assert_float_eq!(angle.to_degrees(), 90.0, abs <= 0.0001);
+///
+/// assert!("ninety degrees".parse::<Angle32>().is_err());
+/// ```
+impl<F: Float + FromStr> FromStr for Angle<F> {
+    type Err = ParseAngleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = parse(s)?;
+        Ok(match unit {
+            Unit::Radians => Self::from_radians(value),
+            Unit::Degrees => Self::from_degrees(value),
+            Unit::Turns => Self::from_turns(value),
+            Unit::Gradians => Self::from_gradians(value),
+        })
+    }
+}
+
+/// Parses an unbounded angle from a number followed by an optional unit suffix.
+///
+/// The accepted format is the same as for [`Angle`].
+///
+/// ```
+/// # use angulus::AngleUnbounded32;
+/// # use float_eq::assert_float_eq;
+/// let angle: AngleUnbounded32 = "540deg".parse().unwrap();
+/// assert_float_eq!(angle.to_turns(), 1.5, abs <= 0.0001);
+/// ```
+impl<F: Float + FromStr> FromStr for AngleUnbounded<F> {
+    type Err = ParseAngleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = parse(s)?;
+        Ok(match unit {
+            Unit::Radians => Self::from_radians(value),
+            Unit::Degrees => Self::from_degrees(value),
+            Unit::Turns => Self::from_turns(value),
+            Unit::Gradians => Self::from_gradians(value),
+        })
+    }
+}
+
+//-------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use crate::{Angle32, Angle64, AngleUnbounded32};
+
+    #[test]
+    fn angle_from_str_suffixes() {
+        macro_rules! check {
+            ($s:expr => $degrees:expr) => {
+                let angle: Angle32 = $s.parse().unwrap();
+                assert_float_eq!(angle.to_degrees(), $degrees, abs <= 1e-4);
+            };
+        }
+
+        check!("90deg" => 90.0);
+        check!("90°" => 90.0);
+        check!("1.5rad" => 85.943_67);
+        check!("0.25tr" => 90.0);
+        check!("100g" => 90.0);
+        check!("100 gon" => 90.0);
+        check!("1.2" => 68.754_94);
+        check!("-45deg" => -45.0);
+        check!("450deg" => 90.0);
+    }
+
+    #[test]
+    fn angle_from_str_whitespaces() {
+        let angle: Angle64 = "  90 deg ".parse().unwrap();
+        assert_float_eq!(angle.to_degrees(), 90.0, abs <= 1e-9);
+
+        let angle: Angle64 = "\t-0.5 tr\n".parse().unwrap();
+        assert_float_eq!(angle.to_degrees(), 180.0, abs <= 1e-9);
+    }
+
+    #[test]
+    fn angle_unbounded_from_str() {
+        let angle: AngleUnbounded32 = "450°".parse().unwrap();
+        assert_float_eq!(angle.to_degrees(), 450.0, abs <= 1e-4);
+
+        let angle: AngleUnbounded32 = "-1.5 tr".parse().unwrap();
+        assert_float_eq!(angle.to_turns(), -1.5, abs <= 1e-6);
+
+        let angle: AngleUnbounded32 = "7".parse().unwrap();
+        assert_float_eq!(angle.to_radians(), 7.0, abs <= 1e-6);
+    }
+
+    #[test]
+    fn angle_from_str_invalid() {
+        macro_rules! check_err {
+            ($($s:expr),* $(,)?) => {
+                $(
+                    assert!($s.parse::<Angle32>().is_err(), "{:?} should not parse", $s);
+                    assert!($s.parse::<AngleUnbounded32>().is_err(), "{:?} should not parse", $s);
+                )*
+            };
+        }
+
+        check_err!(
+            "",
+            "   ",
+            "deg",
+            "°",
+            "abc",
+            "90 degrees",
+            "90 foo",
+            "90deg rad",
+            "1..2"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn angle_from_str_error_messages() {
+        assert_eq!(
+            "".parse::<Angle32>().unwrap_err().to_string(),
+            "cannot parse angle from empty string"
+        );
+        assert_eq!(
+            "abc".parse::<Angle32>().unwrap_err().to_string(),
+            "invalid angle value"
+        );
+    }
+}
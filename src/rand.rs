@@ -60,6 +60,13 @@
 //! let x = thread_rng().gen_range(high..=low);
 //! // panic: "cannot sample empty range"
 //! ```
+//!
+//! # Circular distributions
+//!
+//! With the `std`, `libm` or `cordic` feature, this module also provides distributions of angles clustered around a mean direction:
+//!
+//! - `VonMises`: the [von Mises distribution](https://en.wikipedia.org/wiki/Von_Mises_distribution).
+//! - `WrappedNormal`: the [wrapped normal distribution](https://en.wikipedia.org/wiki/Wrapped_normal_distribution).
 
 use core::ops::{Range, RangeInclusive};
 
@@ -70,7 +77,9 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::float::Float;
-use crate::units::{Degrees, Gradians, Radians, Turns};
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+use crate::float::FloatMath;
+use crate::units::{Arcminutes, Arcseconds, Degrees, Gon, Gradians, Hours, Mils, Radians, Turns};
 use crate::{Angle, AngleUnbounded};
 
 //-------------------------------------------------------------------
@@ -124,7 +133,9 @@ macro_rules! impl_distribution_for_unit {
     };
 }
 
-impl_distribution_for_unit!(Radians, Degrees, Turns, Gradians);
+impl_distribution_for_unit!(
+    Radians, Degrees, Turns, Gradians, Gon, Arcminutes, Arcseconds, Mils, Hours
+);
 
 //-------------------------------------------------------------------
 // Range
@@ -269,6 +280,199 @@ where
     }
 }
 
+//-------------------------------------------------------------------
+// Von Mises
+//-------------------------------------------------------------------
+
+/// The [von Mises distribution](https://en.wikipedia.org/wiki/Von_Mises_distribution),
+/// i.e. the circular analogue of the normal distribution.
+///
+/// The generated angles are clustered around the mean direction `mu`, the concentration `kappa`
+/// controls how tightly: a concentration of zero is the uniform distribution on the circle and
+/// large concentrations approach a normal distribution of standard deviation `1 / sqrt(kappa)` radians.
+///
+/// The samples are generated with the rejection algorithm of Best and Fisher (1979).
+///
+/// ```
+/// # use angulus::{Angle32, rand::VonMises};
+/// # use ::rand::distributions::Distribution;
+/// let heading = VonMises::new(Angle32::DEG_90, 50.0);
+/// let angle = heading.sample(&mut ::rand::thread_rng());
+///
+/// assert!(angle.angular_distance(Angle32::DEG_90).to_degrees() < 90.0);
+/// ```
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VonMises<F> {
+    mu: Angle<F>,
+    kappa: F,
+    /// The `r` parameter of the Best–Fisher algorithm, or `NaN` for a uniform distribution.
+    r: F,
+}
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: FloatMath> VonMises<F> {
+    /// Creates a new von Mises distribution with the mean direction `mu` and the concentration `kappa`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kappa` is negative or `NaN`.
+    #[must_use]
+    pub fn new(mu: Angle<F>, kappa: F) -> Self {
+        assert!(
+            kappa >= F::ZERO,
+            "the concentration must be non-negative and not NaN"
+        );
+
+        // Below the machine epsilon, the distribution cannot be distinguished from the uniform one.
+        let r = if kappa < F::EPSILON {
+            F::NAN
+        } else {
+            let two = F::ONE + F::ONE;
+            let tau = F::ONE + (F::ONE + two * two * kappa * kappa).sqrt();
+            // Same as `(tau - sqrt(2 tau)) / (2 kappa)`, without the cancellation for small `kappa`.
+            let rho = two * kappa / (tau + (two * tau).sqrt());
+            (F::ONE + rho * rho) / (two * rho)
+        };
+
+        Self { mu, kappa, r }
+    }
+
+    /// The mean direction of the distribution.
+    #[must_use]
+    #[inline]
+    pub fn mu(&self) -> Angle<F> {
+        self.mu
+    }
+
+    /// The concentration of the distribution.
+    #[must_use]
+    #[inline]
+    pub fn kappa(&self) -> F {
+        self.kappa
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: FloatMath> Distribution<Angle<F>> for VonMises<F>
+where
+    Standard: Distribution<F> + Distribution<Angle<F>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle<F> {
+        if self.r.is_nan() {
+            return rng.gen();
+        }
+
+        let two = F::ONE + F::ONE;
+        let r = self.r;
+
+        let f = loop {
+            let u1: F = rng.gen();
+            let u2: F = rng.gen();
+
+            let z = (F::PI * u1).cos();
+            let f = (F::ONE + r * z) / (r + z);
+            let c = self.kappa * (r - f);
+
+            if c * (two - c) > u2 || (c / u2).ln() + F::ONE >= c {
+                break f;
+            }
+        };
+
+        // `f` may slightly exceed `[-1, 1]` because of rounding errors.
+        let f = if f > F::ONE {
+            F::ONE
+        } else if f < -F::ONE {
+            -F::ONE
+        } else {
+            f
+        };
+
+        let delta = Angle::acos(f);
+        if rng.gen::<bool>() {
+            self.mu + delta
+        } else {
+            self.mu - delta
+        }
+    }
+}
+
+//-------------------------------------------------------------------
+// Wrapped normal
+//-------------------------------------------------------------------
+
+/// The [wrapped normal distribution](https://en.wikipedia.org/wiki/Wrapped_normal_distribution),
+/// i.e. a normal distribution of radians wrapped around the circle.
+///
+/// The generated angles are clustered around `mean` with the standard deviation `std_dev` in radians.
+/// A small standard deviation is close to the [von Mises distribution][VonMises] with a concentration of
+/// `1 / std_dev²`, while a standard deviation of a few radians is close to the uniform distribution on the circle:
+/// the [mean resultant length][crate::stats::resultant_length] of the samples is `exp(-std_dev² / 2)`.
+///
+/// ```
+/// # use angulus::{Angle32, rand::WrappedNormal};
+/// # use ::rand::distributions::Distribution;
+/// let noise = WrappedNormal::new(Angle32::DEG_90, 0.01);
+/// let angle = noise.sample(&mut ::rand::thread_rng());
+///
+/// assert!(angle.angular_distance(Angle32::DEG_90).to_degrees() < 90.0);
+/// ```
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WrappedNormal<F> {
+    mean: Angle<F>,
+    std_dev: F,
+}
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: FloatMath> WrappedNormal<F> {
+    /// Creates a new wrapped normal distribution with the mean direction `mean` and
+    /// the standard deviation `std_dev` in radians.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `std_dev` is negative or `NaN`.
+    #[must_use]
+    pub fn new(mean: Angle<F>, std_dev: F) -> Self {
+        assert!(
+            std_dev >= F::ZERO,
+            "the standard deviation must be non-negative and not NaN"
+        );
+        Self { mean, std_dev }
+    }
+
+    /// The mean direction of the distribution.
+    #[must_use]
+    #[inline]
+    pub fn mean(&self) -> Angle<F> {
+        self.mean
+    }
+
+    /// The standard deviation of the distribution, in radians.
+    #[must_use]
+    #[inline]
+    pub fn std_dev(&self) -> F {
+        self.std_dev
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: FloatMath> Distribution<Angle<F>> for WrappedNormal<F>
+where
+    Standard: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle<F> {
+        let two = F::ONE + F::ONE;
+
+        // Box–Muller transform, `u1` is in `(0, 1]` to avoid `ln(0)`.
+        let u1 = F::ONE - rng.gen::<F>();
+        let u2: F = rng.gen();
+        let z = (-two * u1.ln()).sqrt() * (F::TAU * u2).cos();
+
+        Angle::from_radians(self.mean.to_radians() + self.std_dev * z)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rand::Rng;
@@ -290,6 +494,11 @@ mod tests {
                     let _: Degrees<$angle> = rand::random();
                     let _: Turns<$angle> = rand::random();
                     let _: Gradians<$angle> = rand::random();
+                    let _: Gon<$angle> = rand::random();
+                    let _: Arcminutes<$angle> = rand::random();
+                    let _: Arcseconds<$angle> = rand::random();
+                    let _: Mils<$angle> = rand::random();
+                    let _: Hours<$angle> = rand::random();
 
                     let _: $angle = rng.gen_range($angle::ZERO..$angle::RAD_PI);
                     let _: $angle = rng.gen_range($angle::ZERO..=$angle::RAD_PI);
@@ -299,4 +508,126 @@ mod tests {
 
         check!(Angle32, Angle64, AngleUnbounded32, AngleUnbounded64);
     }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn von_mises_is_centered_on_mu() {
+        use rand::distributions::Distribution;
+        use rand::SeedableRng;
+
+        use crate::rand::VonMises;
+        use crate::stats::{circular_mean, resultant_length};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+
+        for mu in [
+            Angle64::ZERO,
+            Angle64::DEG_90,
+            Angle64::DEG_180,
+            -Angle64::DEG_60,
+        ] {
+            let distribution = VonMises::new(mu, 100.0);
+
+            let mean = circular_mean((0..1000).map(|_| distribution.sample(&mut rng)));
+            assert!(mean.angular_distance(mu).to_degrees() < 1.0);
+            assert!(resultant_length((0..1000).map(|_| distribution.sample(&mut rng))) > 0.99);
+        }
+
+        let distribution = VonMises::new(Angle32::DEG_180, 1e6);
+        for _ in 0..100 {
+            let angle = distribution.sample(&mut rng);
+            assert!(angle.angular_distance(Angle32::DEG_180).to_degrees() < 1.0);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn von_mises_zero_concentration_is_uniform() {
+        use rand::distributions::Distribution;
+        use rand::SeedableRng;
+
+        use crate::rand::VonMises;
+        use crate::stats::resultant_length;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+
+        for kappa in [0.0, 1e-20] {
+            let distribution = VonMises::new(Angle64::DEG_90, kappa);
+            assert!(resultant_length((0..10_000).map(|_| distribution.sample(&mut rng))) < 0.05);
+        }
+
+        let distribution = VonMises::new(Angle32::DEG_90, 1e-6);
+        for _ in 0..10_000 {
+            assert!(!distribution.sample(&mut rng).is_nan());
+        }
+        assert!(resultant_length((0..10_000).map(|_| distribution.sample(&mut rng))) < 0.05);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    #[should_panic = "the concentration must be non-negative and not NaN"]
+    fn von_mises_negative_concentration() {
+        let _ = crate::rand::VonMises::new(Angle32::ZERO, -1.0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn wrapped_normal_is_centered_on_mean() {
+        use rand::distributions::Distribution;
+        use rand::SeedableRng;
+
+        use crate::rand::WrappedNormal;
+        use crate::stats::{circular_mean, resultant_length};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+
+        for mean in [
+            Angle64::ZERO,
+            Angle64::DEG_90,
+            Angle64::DEG_180,
+            -Angle64::DEG_60,
+        ] {
+            let distribution = WrappedNormal::new(mean, 0.1);
+
+            let mean_direction = circular_mean((0..1000).map(|_| distribution.sample(&mut rng)));
+            assert!(mean_direction.angular_distance(mean).to_degrees() < 1.0);
+            assert!(resultant_length((0..1000).map(|_| distribution.sample(&mut rng))) > 0.99);
+        }
+
+        let distribution = WrappedNormal::new(Angle32::DEG_90, 0.0);
+        let angle = distribution.sample(&mut rng);
+        assert!(angle.angular_distance(Angle32::DEG_90).to_radians() < 1e-6);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn wrapped_normal_wraps_large_std_dev() {
+        use rand::distributions::Distribution;
+        use rand::SeedableRng;
+
+        use crate::rand::WrappedNormal;
+        use crate::stats::resultant_length;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+
+        let distribution = WrappedNormal::new(Angle64::DEG_180, 1.0);
+        // `exp(-σ²/2)` with `σ = 1`.
+        let expected = 0.606_530_659_712_633_4;
+        let length = resultant_length((0..10_000).map(|_| distribution.sample(&mut rng)));
+        assert!((length - expected).abs() < 0.02);
+
+        let distribution = WrappedNormal::new(Angle64::DEG_180, 10.0);
+        assert!((0..10_000).all(|_| {
+            let radians = distribution.sample(&mut rng).to_radians();
+            -core::f64::consts::PI < radians && radians <= core::f64::consts::PI
+        }));
+        assert!(resultant_length((0..10_000).map(|_| distribution.sample(&mut rng))) < 0.05);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    #[should_panic = "the standard deviation must be non-negative and not NaN"]
+    fn wrapped_normal_negative_std_dev() {
+        let _ = crate::rand::WrappedNormal::new(Angle32::ZERO, -1.0);
+    }
 }
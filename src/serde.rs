@@ -35,11 +35,28 @@
 //! assert_float_eq!(foo.tr.0.to_turns(), 0.5, abs <= 0.000001);
 //! assert_float_eq!(foo.grad.0.to_gradians(), 50.0, abs <= 0.000001);
 //! ```
+//!
+//! # Degrees, minutes and seconds
+//!
+//! The [`dms`] module (de)serializes an [`Angle`] as its degrees, minutes and seconds.
+//! Use it with `#[serde(with = "angulus::serde::dms")]`.
+//!
+//! # Numbers or strings
+//!
+//! The [`flexible`] module deserializes an [`Angle`] from either a number of radians or
+//! a string with a unit suffix, like `"90deg"`.
+//! Use it with `#[serde(with = "angulus::serde::flexible")]`.
+//!
+//! # Rejecting non-finite values
+//!
+//! By default, an infinite or `NaN` value (where the format allows it) is deserialized as a `NaN` angle.
+//! The [`strict`] module returns an error instead, which is useful to validate untrusted input.
+//! Use it with `#[serde(with = "angulus::serde::strict")]`.
 
 use serde::{Deserialize, Serialize};
 
 use crate::float::Float;
-use crate::units::{Degrees, Gradians, Radians, Turns};
+use crate::units::{Arcminutes, Arcseconds, Degrees, Gon, Gradians, Hours, Mils, Radians, Turns};
 use crate::{Angle, AngleUnbounded};
 
 //-------------------------------------------------------------------
@@ -118,6 +135,334 @@ impl_serde_for_unit!(Radians);
 impl_serde_for_unit!(Degrees);
 impl_serde_for_unit!(Turns);
 impl_serde_for_unit!(Gradians);
+impl_serde_for_unit!(Gon);
+impl_serde_for_unit!(Arcminutes);
+impl_serde_for_unit!(Arcseconds);
+impl_serde_for_unit!(Mils);
+impl_serde_for_unit!(Hours);
+
+//-------------------------------------------------------------------
+
+pub mod dms {
+    //! (De)serializes an [`Angle`] as its degrees, minutes and seconds.
+    //!
+    //! The angle is serialized as a `[degrees, minutes, seconds]` sequence and
+    //! deserialized from either a sequence or a `{ "d": .., "m": .., "s": .. }` map.
+    //!
+    //! The sign of the angle is carried by the degrees, so a negative angle of less than
+    //! one degree has `-0.0` degrees.
+    //!
+    //! ```
+    //! # use angulus::Angle64;
+    //! # use float_eq::assert_float_eq;
+    //! # use ::serde::{Serialize, Deserialize};
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Place {
+    //!     #[serde(with = "angulus::serde::dms")]
+    //!     latitude: Angle64,
+    //!     #[serde(with = "angulus::serde::dms")]
+    //!     longitude: Angle64,
+    //! }
+    //!
+    //! let json = r#"{ "latitude": [48, 51, 24], "longitude": { "d": -2, "m": 21, "s": 3 } }"#;
+    //! let place: Place = serde_json::from_str(json).unwrap();
+    //!
+    //! assert_float_eq!(place.latitude.to_degrees(), 48.856_666, abs <= 0.000_001);
+    //! assert_float_eq!(place.longitude.to_degrees(), -2.350_833, abs <= 0.000_001);
+    //!
+    //! let json = serde_json::to_string(&place).unwrap();
+    //! assert!(json.starts_with(r#"{"latitude":[48.0,51,24.0"#));
+    //! ```
+
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde::de::{self, MapAccess, SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::float::Float;
+    use crate::units::Dms;
+    use crate::Angle;
+
+    const FIELDS: &[&str] = &["d", "m", "s"];
+
+    /// Serializes an angle as a `[degrees, minutes, seconds]` sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the serializer, if any.
+    pub fn serialize<F, S>(angle: &Angle<F>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Float + Serialize,
+        S: Serializer,
+    {
+        let dms = Dms::from_angle(*angle);
+        let degrees = F::from_i64(i64::from(dms.degrees));
+
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&if dms.negative { -degrees } else { degrees })?;
+        tuple.serialize_element(&dms.minutes)?;
+        tuple.serialize_element(&dms.seconds)?;
+        tuple.end()
+    }
+
+    /// Deserializes an angle from a `[degrees, minutes, seconds]` sequence or
+    /// a `{ "d": .., "m": .., "s": .. }` map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is neither a sequence nor a map of three numbers.
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<Angle<F>, D::Error>
+    where
+        F: Float + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Dms", FIELDS, DmsVisitor(PhantomData))
+    }
+
+    /// Recombines the components into an angle, the sign of `degrees` being the sign of the angle.
+    fn to_angle<F: Float>(degrees: F, minutes: F, seconds: F) -> Angle<F> {
+        let arcseconds =
+            degrees.abs() * F::from_u64(3600) + minutes.abs() * F::from_u64(60) + seconds.abs();
+        Angle::from_arcseconds(if degrees.signum() < F::ZERO {
+            -arcseconds
+        } else {
+            arcseconds
+        })
+    }
+
+    enum Field {
+        Degrees,
+        Minutes,
+        Seconds,
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct FieldVisitor;
+
+            impl Visitor<'_> for FieldVisitor {
+                type Value = Field;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("`d`, `m` or `s`")
+                }
+
+                fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                    match value {
+                        "d" => Ok(Field::Degrees),
+                        "m" => Ok(Field::Minutes),
+                        "s" => Ok(Field::Seconds),
+                        _ => Err(de::Error::unknown_field(value, FIELDS)),
+                    }
+                }
+            }
+
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    struct DmsVisitor<F>(PhantomData<F>);
+
+    impl<'de, F: Float + Deserialize<'de>> Visitor<'de> for DmsVisitor<F> {
+        type Value = Angle<F>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an angle as `[degrees, minutes, seconds]`")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let degrees = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let minutes = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            let seconds = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+            Ok(to_angle(degrees, minutes, seconds))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut degrees = None;
+            let mut minutes = None;
+            let mut seconds = None;
+
+            while let Some(key) = map.next_key()? {
+                let (slot, name) = match key {
+                    Field::Degrees => (&mut degrees, "d"),
+                    Field::Minutes => (&mut minutes, "m"),
+                    Field::Seconds => (&mut seconds, "s"),
+                };
+                if slot.is_some() {
+                    return Err(de::Error::duplicate_field(name));
+                }
+                *slot = Some(map.next_value()?);
+            }
+
+            Ok(to_angle(
+                degrees.ok_or_else(|| de::Error::missing_field("d"))?,
+                minutes.ok_or_else(|| de::Error::missing_field("m"))?,
+                seconds.ok_or_else(|| de::Error::missing_field("s"))?,
+            ))
+        }
+    }
+}
+
+//-------------------------------------------------------------------
+
+pub mod flexible {
+    //! (De)serializes an [`Angle`] from either a number or a string.
+    //!
+    //! A number is read as radians and a string is parsed with the [`FromStr`] implementation
+    //! of [`Angle`], so it can have a unit suffix (e.g. `"90deg"` or `"0.25 tr"`).
+    //!
+    //! The angle is always serialized as a number of radians.
+    //!
+    //! ```
+    //! # use angulus::Angle32;
+    //! # use float_eq::assert_float_eq;
+    //! # use ::serde::Deserialize;
+    //! #[derive(Deserialize)]
+    //! struct Config {
+    //!     #[serde(with = "angulus::serde::flexible")]
+    //!     angle: Angle32,
+    //! }
+    //!
+    //! let a: Config = serde_json::from_str(r#"{ "angle": 1.5707964 }"#).unwrap();
+    //! let b: Config = serde_json::from_str(r#"{ "angle": "90deg" }"#).unwrap();
+    //!
+    //! assert_float_eq!(a.angle.to_degrees(), 90.0, abs <= 0.0001);
+    //! assert_float_eq!(b.angle.to_degrees(), 90.0, abs <= 0.0001);
+    //! ```
+
+    use core::fmt;
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    use crate::float::{Float, FloatCast};
+    use crate::Angle;
+
+    /// Serializes an angle as a number of radians.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the serializer, if any.
+    pub fn serialize<F, S>(angle: &Angle<F>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Copy + Serialize,
+        S: Serializer,
+    {
+        angle.to_radians().serialize(serializer)
+    }
+
+    /// Deserializes an angle from a number of radians or a string with a unit suffix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is neither a number nor a string that can be parsed as an angle.
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<Angle<F>, D::Error>
+    where
+        F: Float + FromStr,
+        f64: FloatCast<F>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(FlexibleVisitor(PhantomData))
+    }
+
+    struct FlexibleVisitor<F>(PhantomData<F>);
+
+    impl<F> Visitor<'_> for FlexibleVisitor<F>
+    where
+        F: Float + FromStr,
+        f64: FloatCast<F>,
+    {
+        type Value = Angle<F>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a number of radians or a string with a unit suffix")
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            Ok(Angle::from_radians(F::from_i64(value)))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(Angle::from_radians(F::from_u64(value)))
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            Ok(Angle::from_radians(value.cast()))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            value.parse().map_err(E::custom)
+        }
+    }
+}
+
+//-------------------------------------------------------------------
+
+pub mod strict {
+    //! (De)serializes an [`Angle`] as a number of radians, rejecting non-finite values.
+    //!
+    //! See [`Angle::try_from_radians`].
+    //!
+    //! ```
+    //! # use angulus::Angle32;
+    //! # use ::serde::Deserialize;
+    //! #[derive(Deserialize)]
+    //! struct Input {
+    //!     #[serde(with = "angulus::serde::strict")]
+    //!     angle: Angle32,
+    //! }
+    //!
+    //! assert!(serde_json::from_str::<Input>(r#"{ "angle": 1.0 }"#).is_ok());
+    //! // Overflows `f32`.
+    //! assert!(serde_json::from_str::<Input>(r#"{ "angle": 1e300 }"#).is_err());
+    //! ```
+
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::float::Float;
+    use crate::Angle;
+
+    /// Serializes an angle as a number of radians.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the serializer, if any.
+    pub fn serialize<F, S>(angle: &Angle<F>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Copy + Serialize,
+        S: Serializer,
+    {
+        angle.to_radians().serialize(serializer)
+    }
+
+    /// Deserializes an angle from a number of radians.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is infinite or `NaN`, or if it is not a number.
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<Angle<F>, D::Error>
+    where
+        F: Float + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let radians = F::deserialize(deserializer)?;
+        Angle::try_from_radians(radians).map_err(D::Error::custom)
+    }
+}
 
 //-------------------------------------------------------------------
 
@@ -168,6 +513,142 @@ mod tests {
         assert_float_eq!(before.g.0.to_gradians(), after.g, abs <= TOLERANCE);
     }
 
+    #[test]
+    fn dms_round_trip() {
+        #[derive(Serialize, Deserialize)]
+        struct Place {
+            #[serde(with = "crate::serde::dms")]
+            angle: Angle<f64>,
+        }
+
+        for degrees in [0.0, 12.582_222, -12.582_222, -0.5, 179.999, -179.999] {
+            let place = Place {
+                angle: Angle::from_degrees(degrees),
+            };
+
+            let json = serde_json::to_value(&place).unwrap();
+            assert!(json["angle"].is_array());
+
+            let place: Place = serde_json::from_value(json).unwrap();
+            assert_float_eq!(place.angle.to_degrees(), degrees, abs <= 1e-9);
+        }
+
+        let json = serde_json::to_string(&Place {
+            angle: Angle::from_degrees(-0.5),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"angle":[-0.0,30,0.0]}"#);
+    }
+
+    #[test]
+    fn dms_deserialize() {
+        #[derive(Deserialize)]
+        struct Place {
+            #[serde(with = "crate::serde::dms")]
+            angle: Angle<f32>,
+        }
+
+        macro_rules! check {
+            ($json:tt => $degrees:expr) => {
+                let place: Place =
+                    serde_json::from_value(serde_json::json!({ "angle": $json })).unwrap();
+                assert_float_eq!(place.angle.to_degrees(), $degrees, abs <= TOLERANCE);
+            };
+        }
+
+        check!([12, 34, 56] => 12.582_222);
+        check!([-12, 34, 56] => -12.582_222);
+        check!([-0.0, 30, 0] => -0.5);
+        check!({ "d": 12, "m": 34, "s": 56 } => 12.582_222);
+        check!({ "s": 56, "d": -12, "m": 34 } => -12.582_222);
+        check!({ "d": 370, "m": 0, "s": 0.0 } => 10.0);
+
+        for json in [
+            serde_json::json!([12, 34]),
+            serde_json::json!({ "d": 12, "m": 34 }),
+            serde_json::json!({ "d": 12, "m": 34, "s": 56, "x": 0 }),
+            serde_json::json!(12.5),
+        ] {
+            assert!(serde_json::from_value::<Place>(serde_json::json!({ "angle": json })).is_err());
+        }
+
+        let json = r#"{ "angle": { "d": 12, "d": 12, "m": 34, "s": 56 } }"#;
+        assert!(serde_json::from_str::<Place>(json).is_err());
+    }
+
+    #[test]
+    fn flexible_deserialize() {
+        #[derive(Serialize, Deserialize)]
+        struct Config {
+            #[serde(with = "crate::serde::flexible")]
+            angle: Angle<f32>,
+        }
+
+        macro_rules! check {
+            ($json:expr => $degrees:expr) => {
+                let config: Config =
+                    serde_json::from_value(serde_json::json!({ "angle": $json })).unwrap();
+                assert_float_eq!(config.angle.to_degrees(), $degrees, abs <= 1e-4);
+            };
+        }
+
+        check!(1.570_796_4 => 90.0);
+        check!("90deg" => 90.0);
+        check!("90°" => 90.0);
+        check!("0.25 tr" => 90.0);
+        check!("100g" => 90.0);
+        check!("1.5707964" => 90.0);
+        check!(3 => 171.887_34);
+        check!(-3 => -171.887_34);
+
+        for json in [
+            serde_json::json!("ninety"),
+            serde_json::json!(""),
+            serde_json::json!([90]),
+            serde_json::json!(null),
+        ] {
+            assert!(
+                serde_json::from_value::<Config>(serde_json::json!({ "angle": json })).is_err()
+            );
+        }
+
+        let config = Config {
+            angle: Angle::from_radians(0.5),
+        };
+        assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"angle":0.5}"#);
+    }
+
+    #[test]
+    fn strict_deserialize() {
+        use serde::de::value::{Error, F64Deserializer};
+        use serde::de::{Error as _, IntoDeserializer};
+
+        use crate::NonFiniteError;
+
+        #[derive(Deserialize)]
+        struct Input {
+            #[serde(with = "crate::serde::strict")]
+            angle: Angle<f32>,
+        }
+
+        fn deserialize(value: f64) -> Result<Angle<f64>, Error> {
+            let deserializer: F64Deserializer<Error> = value.into_deserializer();
+            crate::serde::strict::deserialize(deserializer)
+        }
+
+        assert_eq!(deserialize(1.0), Ok(Angle::from_radians(1.0)));
+        assert_eq!(deserialize(10.0), Ok(Angle::from_radians(10.0)));
+
+        for value in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            assert_eq!(deserialize(value), Err(Error::custom(NonFiniteError)));
+        }
+
+        let input: Input = serde_json::from_str(r#"{ "angle": 0.5 }"#).unwrap();
+        assert_float_eq!(input.angle.to_radians(), 0.5, abs <= TOLERANCE);
+        // Overflows `f32`.
+        assert!(serde_json::from_str::<Input>(r#"{ "angle": 1e300 }"#).is_err());
+    }
+
     #[test]
     fn should_deserialize() {
         #[derive(Deserialize)]
@@ -0,0 +1,221 @@
+//! Directional statistics on angles.
+//!
+//! Averaging angles by averaging their values is wrong near the seam of the main range:
+//! the mean of 170° and -170° is 180°, not 0°.
+//! The functions of this module treat angles as unit vectors instead.
+//!
+//! ```
+//! # use angulus::{Angle32, stats::circular_mean};
+//! # use float_eq::assert_float_eq;
+//! let angles = [Angle32::from_degrees(170.0), Angle32::from_degrees(-170.0)];
+//!
+//! let mean = circular_mean(angles);
+//! assert_float_eq!(mean.to_degrees().abs(), 180.0, abs <= 0.0001);
+//! ```
+
+use crate::float::FloatMath;
+use crate::Angle;
+
+/// The sum of the unit vectors of the angles and the number of angles.
+struct Resultant<F> {
+    sum_sin: F,
+    sum_cos: F,
+    count: usize,
+}
+
+impl<F: FloatMath> Resultant<F> {
+    fn new<I>(angles: I) -> Self
+    where
+        I: IntoIterator<Item = Angle<F>>,
+    {
+        let mut resultant = Self {
+            sum_sin: F::ZERO,
+            sum_cos: F::ZERO,
+            count: 0,
+        };
+
+        for angle in angles {
+            let (sin, cos) = angle.sin_cos();
+            resultant.sum_sin += sin;
+            resultant.sum_cos += cos;
+            resultant.count += 1;
+        }
+
+        resultant
+    }
+}
+
+/// Computes the circular mean of the angles, i.e. the direction of the sum of their unit vectors.
+///
+/// Returns a `NaN` angle if there are no angles.
+///
+/// If the angles cancel each other (e.g. two opposite angles), the mean direction is
+/// undefined and the result is unspecified.
+pub fn circular_mean<F, I>(angles: I) -> Angle<F>
+where
+    F: FloatMath,
+    I: IntoIterator<Item = Angle<F>>,
+{
+    let Resultant {
+        sum_sin,
+        sum_cos,
+        count,
+    } = Resultant::new(angles);
+
+    if count == 0 {
+        Angle::from_radians_unchecked(F::NAN)
+    } else {
+        Angle::from_atan2(sum_sin, sum_cos)
+    }
+}
+
+/// Computes the weighted circular mean of the angles, i.e. the direction of the sum of their
+/// unit vectors scaled by their weight.
+///
+/// The weights are expected to be non-negative.
+/// Returns a `NaN` angle if there are no angles or if all the weights are zero.
+///
+/// ```
+/// # use angulus::{Angle32, stats::weighted_circular_mean};
+/// let readings = [(Angle32::ZERO, 3.0), (Angle32::DEG_90, 1.0)];
+///
+/// let mean = weighted_circular_mean(readings);
+/// assert!(mean.to_degrees() < 45.0);
+/// ```
+pub fn weighted_circular_mean<F, I>(angles: I) -> Angle<F>
+where
+    F: FloatMath,
+    I: IntoIterator<Item = (Angle<F>, F)>,
+{
+    let mut sum_sin = F::ZERO;
+    let mut sum_cos = F::ZERO;
+    let mut sum_weight = F::ZERO;
+
+    for (angle, weight) in angles {
+        let (sin, cos) = angle.sin_cos();
+        sum_sin += weight * sin;
+        sum_cos += weight * cos;
+        sum_weight += weight;
+    }
+
+    if sum_weight == F::ZERO {
+        Angle::from_radians_unchecked(F::NAN)
+    } else {
+        Angle::from_atan2(sum_sin, sum_cos)
+    }
+}
+
+/// Computes the mean resultant length `R` of the angles, i.e. the length of the mean of their unit vectors.
+///
+/// `R` is in the range `[0, 1]`: a value near `1` means the angles are tightly clustered,
+/// a value near `0` means they are widely dispersed (or cancel each other).
+///
+/// Returns `NaN` if there are no angles.
+pub fn resultant_length<F, I>(angles: I) -> F
+where
+    F: FloatMath,
+    I: IntoIterator<Item = Angle<F>>,
+{
+    let Resultant {
+        sum_sin,
+        sum_cos,
+        count,
+    } = Resultant::new(angles);
+
+    let count = F::from_u64(count as u64);
+    (sum_sin * sum_sin + sum_cos * sum_cos).sqrt() / count
+}
+
+/// Computes the circular variance of the angles, i.e. `1 - R` where `R` is the
+/// [mean resultant length][resultant_length].
+///
+/// The variance is in the range `[0, 1]`: `0` means all the angles are the same.
+///
+/// Returns `NaN` if there are no angles.
+pub fn circular_variance<F, I>(angles: I) -> F
+where
+    F: FloatMath,
+    I: IntoIterator<Item = Angle<F>>,
+{
+    F::ONE - resultant_length(angles)
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use crate::Angle32;
+
+    use super::*;
+
+    #[test]
+    fn circular_mean_across_the_seam() {
+        let angles = [Angle32::from_degrees(170.0), Angle32::from_degrees(-170.0)];
+        let mean = circular_mean(angles);
+        assert_float_eq!(mean.to_degrees().abs(), 180.0, abs <= 1e-4);
+    }
+
+    #[test]
+    fn circular_mean_of_close_angles() {
+        let angles = [10.0, 20.0, 30.0].map(Angle32::from_degrees);
+        let mean = circular_mean(angles);
+        assert_float_eq!(mean.to_degrees(), 20.0, abs <= 1e-4);
+    }
+
+    #[test]
+    fn circular_mean_of_nothing_is_nan() {
+        assert!(circular_mean::<f32, _>([]).is_nan());
+    }
+
+    #[test]
+    fn weighted_circular_mean_favors_heavier_angle() {
+        let a = Angle32::from_degrees(10.0);
+        let b = Angle32::from_degrees(70.0);
+
+        let mean = weighted_circular_mean([(a, 1.0), (b, 1.0)]);
+        assert_float_eq!(mean.to_degrees(), 40.0, abs <= 1e-4);
+
+        let mean = weighted_circular_mean([(a, 1.0), (b, 4.0)]);
+        assert!(mean.angular_distance(b).to_radians() < mean.angular_distance(a).to_radians());
+
+        let mean = weighted_circular_mean([(a, 4.0), (b, 1.0)]);
+        assert!(mean.angular_distance(a).to_radians() < mean.angular_distance(b).to_radians());
+
+        let mean = weighted_circular_mean([(a, 1.0), (b, 0.0)]);
+        assert_float_eq!(mean.to_degrees(), 10.0, abs <= 1e-4);
+    }
+
+    #[test]
+    fn weighted_circular_mean_of_zero_weights_is_nan() {
+        let a = Angle32::from_degrees(10.0);
+        let b = Angle32::from_degrees(70.0);
+
+        assert!(weighted_circular_mean([(a, 0.0), (b, 0.0)]).is_nan());
+        assert!(weighted_circular_mean::<f32, _>([]).is_nan());
+    }
+
+    #[test]
+    fn circular_variance_of_identical_angles() {
+        let angles = [Angle32::DEG_60; 5];
+        assert_float_eq!(resultant_length(angles), 1.0, abs <= 1e-6);
+        assert_float_eq!(circular_variance(angles), 0.0, abs <= 1e-6);
+    }
+
+    #[test]
+    fn circular_variance_of_opposed_angles() {
+        let angles = [
+            Angle32::ZERO,
+            Angle32::DEG_90,
+            Angle32::DEG_180,
+            -Angle32::DEG_90,
+        ];
+        assert_float_eq!(resultant_length(angles), 0.0, abs <= 1e-6);
+        assert_float_eq!(circular_variance(angles), 1.0, abs <= 1e-6);
+    }
+
+    #[test]
+    fn circular_variance_of_nothing_is_nan() {
+        assert!(resultant_length::<f32, _>([]).is_nan());
+        assert!(circular_variance::<f32, _>([]).is_nan());
+    }
+}
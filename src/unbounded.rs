@@ -1,8 +1,10 @@
 use core::fmt::Debug;
 use core::iter::Sum;
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 
-use crate::float::Float;
+use crate::float::{Float, FloatCast};
 use crate::macros::{forward_ref_binop, forward_ref_op_assign, forward_ref_unop};
 use crate::Angle;
 
@@ -22,7 +24,15 @@ use crate::Angle;
 ///
 /// assert_ne!(a, b);
 /// ```
-
+///
+/// # Hashing
+///
+/// Like the floating point types, [`AngleUnbounded<f32>`] and [`AngleUnbounded<f64>`] implement neither
+/// [`Eq`] nor [`Hash`]: `0.0` and `-0.0` radians are equal but have different bits, and a `NaN`
+/// angle is not equal to itself.
+///
+/// To use an angle as a key of a map, use the bits of its [canonical form][AngleUnbounded::canonicalize]:
+/// angles that are equal have the same canonical bits.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct AngleUnbounded<F> {
@@ -41,6 +51,12 @@ impl<F: Float> AngleUnbounded<F> {
     ///
     /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
     pub const EPSILON: Self = AngleUnbounded::from_radians(F::DOUBLE_EPSILON);
+
+    /// The [golden angle](https://en.wikipedia.org/wiki/Golden_angle) (≈ 137.5°),
+    /// i.e. `τ * (1 - 1/φ)` radians where `φ` is the golden ratio.
+    ///
+    /// Rotating by this angle places points evenly around a center, as in a sunflower head.
+    pub const GOLDEN: Self = AngleUnbounded::from_radians(F::GOLDEN_ANGLE);
 }
 
 impl<F: Float> AngleUnbounded<F> {
@@ -56,6 +72,10 @@ impl<F: Float> AngleUnbounded<F> {
     pub const RAD_FRAC_PI_6: Self = AngleUnbounded::from_radians(F::FRAC_PI_6);
     /// The angle of π/8 radians.
     pub const RAD_FRAC_PI_8: Self = AngleUnbounded::from_radians(F::FRAC_PI_8);
+    /// The angle of π/12 radians.
+    pub const RAD_FRAC_PI_12: Self = AngleUnbounded::from_radians(F::FRAC_PI_12);
+    /// The angle of π/16 radians.
+    pub const RAD_FRAC_PI_16: Self = AngleUnbounded::from_radians(F::FRAC_PI_16);
 }
 
 impl<F: Float> AngleUnbounded<F> {
@@ -71,9 +91,18 @@ impl<F: Float> AngleUnbounded<F> {
     pub const DEG_30: Self = Self::RAD_FRAC_PI_6;
     /// The angle of 22.5°.
     pub const DEG_22_5: Self = Self::RAD_FRAC_PI_8;
+    /// The angle of 15°.
+    pub const DEG_15: Self = Self::RAD_FRAC_PI_12;
+    /// The angle of 11.25°.
+    pub const DEG_11_25: Self = Self::RAD_FRAC_PI_16;
 }
 
 impl<F: Float> AngleUnbounded<F> {
+    /// The angle of a full circle (1 turn), i.e. `τ` radians.
+    ///
+    /// [`Angle`] has no such constant because a full turn wraps to [`Angle::ZERO`].
+    pub const FULL_TURN: Self = AngleUnbounded::from_radians(F::TAU);
+
     /// The angle of a half of a circle (1/2 turns).
     pub const HALF: Self = Self::RAD_PI;
     /// The angle of a quarter of a circle (1/4 turns).
@@ -152,6 +181,30 @@ impl<F: Float> AngleUnbounded<F> {
     pub fn from_gradians(gradians: F) -> Self {
         Self::from_radians(gradians * F::GRAD_TO_RAD)
     }
+
+    /// Creates a new unbounded angle from a value in arcminutes.
+    #[inline]
+    pub fn from_arcminutes(arcminutes: F) -> Self {
+        Self::from_radians(arcminutes * F::ARCMIN_TO_RAD)
+    }
+
+    /// Creates a new unbounded angle from a value in arcseconds.
+    #[inline]
+    pub fn from_arcseconds(arcseconds: F) -> Self {
+        Self::from_radians(arcseconds * F::ARCSEC_TO_RAD)
+    }
+
+    /// Creates a new unbounded angle from a value in NATO mils.
+    #[inline]
+    pub fn from_mils(mils: F) -> Self {
+        Self::from_radians(mils * F::MIL_TO_RAD)
+    }
+
+    /// Creates a new unbounded angle from a value in hours.
+    #[inline]
+    pub fn from_hours(hours: F) -> Self {
+        Self::from_radians(hours * F::HOUR_TO_RAD)
+    }
 }
 
 //-------------------------------------------------------------------
@@ -188,6 +241,88 @@ impl<F: Float> AngleUnbounded<F> {
     pub fn to_gradians(self) -> F {
         self.radians * F::RAD_TO_GRAD
     }
+
+    /// The value of the unbounded angle in arcminutes.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_arcminutes(self) -> F {
+        self.radians * F::RAD_TO_ARCMIN
+    }
+
+    /// The value of the unbounded angle in arcseconds.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_arcseconds(self) -> F {
+        self.radians * F::RAD_TO_ARCSEC
+    }
+
+    /// The value of the unbounded angle in NATO mils.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_mils(self) -> F {
+        self.radians * F::RAD_TO_MIL
+    }
+
+    /// The value of the unbounded angle in hours.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_hours(self) -> F {
+        self.radians * F::RAD_TO_HOUR
+    }
+}
+
+impl<F: Float> AngleUnbounded<F> {
+    /// Returns `true` if this angle is NaN.
+    #[must_use]
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.radians.is_nan()
+    }
+
+    /// Returns `true` if this angle is neither infinite nor NaN.
+    #[must_use]
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.radians.is_finite()
+    }
+
+    /// Returns `true` if this angle has a positive sign, including `+0.0` and positive infinity.
+    #[must_use]
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        self.radians.is_sign_positive()
+    }
+
+    /// Returns `true` if this angle has a negative sign, including `-0.0` and negative infinity.
+    #[must_use]
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self.radians.is_sign_negative()
+    }
+
+    /// Returns the canonical form of this angle: `-0.0` radians becomes `0.0` and
+    /// every `NaN` becomes the same `NaN`.
+    ///
+    /// Angles that are equal have canonical forms with the same bits, so the bits of the
+    /// canonical value in radians can be used as a key of a map.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// let a = AngleUnbounded::from_radians(-0.0_f32).canonicalize();
+    /// let b = AngleUnbounded::from_radians(0.0_f32).canonicalize();
+    ///
+    /// assert_eq!(a.to_radians().to_bits(), b.to_radians().to_bits());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn canonicalize(self) -> Self {
+        if self.radians.is_nan() {
+            Self::from_radians(F::NAN)
+        } else {
+            // NOTE: `-0.0 + 0.0` is `0.0`, any other value is unchanged.
+            Self::from_radians(self.radians + F::ZERO)
+        }
+    }
 }
 
 //-------------------------------------------------------------------
@@ -210,6 +345,57 @@ impl<F: Copy> From<Angle<F>> for AngleUnbounded<F> {
     }
 }
 
+impl<F: Float> AngleUnbounded<F> {
+    /// Returns the number of complete turns of this angle, rounded toward zero.
+    ///
+    /// Together with [`AngleUnbounded::principal`], it splits the angle into a number of turns
+    /// and a position on the circle: `3.25` turns are `3` turns plus `90°`.
+    ///
+    /// A turn is complete when the angle reaches the floating point value of `n * τ`,
+    /// so an angle created by [`AngleUnbounded::from_turns`] with an integer `n` counts
+    /// exactly `n` turns.
+    ///
+    /// Out of range values saturate to [`i32::MIN`] or [`i32::MAX`], `NaN` returns `0`.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// # use float_eq::assert_float_eq;
+    /// let a = AngleUnbounded::from_turns(3.25_f32);
+    ///
+    /// assert_eq!(a.turns_count(), 3);
+    /// assert_float_eq!(a.principal().to_degrees(), 90.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn turns_count(self) -> i32 {
+        let mut count = (self.radians / F::TAU).trunc_to_i64();
+
+        // The division may be rounded across an integer: compare with the value of
+        // `from_turns(count)` so that `n` turns are counted as exactly `n`.
+        // NOTE: `from_turns(n)` is `n * TURNS_TO_RAD` and `TURNS_TO_RAD` is exactly `TAU`,
+        // so it is the same product as below.
+        let radians = self.radians.abs();
+        let step = if self.radians < F::ZERO { -1 } else { 1 };
+        if (F::from_i64(count.saturating_add(step)) * F::TAU).abs() <= radians {
+            count = count.saturating_add(step);
+        } else if (F::from_i64(count) * F::TAU).abs() > radians {
+            count = count.saturating_sub(step);
+        }
+
+        i32::try_from(count).unwrap_or(if count < 0 { i32::MIN } else { i32::MAX })
+    }
+
+    /// Returns the position of this angle on the circle, i.e. the angle in
+    /// [the main range](crate#the-main-range).
+    ///
+    /// This is the same as [`AngleUnbounded::to_bounded`].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn principal(self) -> Angle<F> {
+        self.to_bounded()
+    }
+}
+
 //-------------------------------------------------------------------
 // Floating point type conversion
 //-------------------------------------------------------------------
@@ -249,11 +435,32 @@ impl From<AngleUnbounded<f32>> for AngleUnbounded<f64> {
     }
 }
 
+impl<F: Float> AngleUnbounded<F> {
+    /// Converts the floating point type to `T`.
+    ///
+    /// This is the generic version of `to_f32` and `to_f64`.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded64;
+    /// let a = AngleUnbounded64::DEG_90;
+    ///
+    /// assert_eq!(a.cast::<f32>(), a.to_f32());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn cast<T: Float>(self) -> AngleUnbounded<T>
+    where
+        F: FloatCast<T>,
+    {
+        AngleUnbounded::from_radians(self.radians.cast())
+    }
+}
+
 //-------------------------------------------------------------------
 // Maths
 //-------------------------------------------------------------------
 
-#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
 impl<F: crate::float::FloatMath> AngleUnbounded<F> {
     /// Computes the sine.
     #[must_use = "this returns the result of the operation, without modifying the original"]
@@ -276,12 +483,420 @@ impl<F: crate::float::FloatMath> AngleUnbounded<F> {
         self.radians.tan()
     }
 
+    /// Computes the secant (`1 / cos`).
+    ///
+    /// Returns an infinite value where the cosine is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn sec(self) -> F {
+        F::ONE / self.radians.cos()
+    }
+
+    /// Computes the cosecant (`1 / sin`).
+    ///
+    /// Returns an infinite value where the sine is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn csc(self) -> F {
+        F::ONE / self.radians.sin()
+    }
+
+    /// Computes the cotangent (`cos / sin`).
+    ///
+    /// Returns an infinite value where the sine is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn cot(self) -> F {
+        let (sin, cos) = self.radians.sin_cos();
+        cos / sin
+    }
+
     /// Simultaneously computes the sine and cosine. Returns `(sin(x), cos(x))`.
     #[must_use = "this returns the result of the operation, without modifying the original"]
     #[inline]
     pub fn sin_cos(self) -> (F, F) {
         self.radians.sin_cos()
     }
+
+    /// Computes the unit vector pointing in the direction of the angle.
+    ///
+    /// Returns `(x, y)` where `x = cos(angle)` and `y = sin(angle)`, i.e.
+    /// the angle `0` points toward the positive x-axis and angles increase counterclockwise.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_vector(self) -> (F, F) {
+        let (sin, cos) = self.radians.sin_cos();
+        (cos, sin)
+    }
+
+    /// Computes the 2×2 matrix of the counterclockwise rotation by this angle.
+    ///
+    /// The matrix is in row-major order, i.e. `matrix[row][column]`:
+    ///
+    /// ```text
+    /// [[cos, -sin],
+    ///  [sin,  cos]]
+    /// ```
+    ///
+    /// and is meant to be multiplied with column vectors.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_rotation_matrix(self) -> [[F; 2]; 2] {
+        let (sin, cos) = self.radians.sin_cos();
+        [[cos, -sin], [sin, cos]]
+    }
+
+    /// Rotates the vector `(x, y)` counterclockwise by this angle.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn rotate_vector(self, (x, y): (F, F)) -> (F, F) {
+        let (sin, cos) = self.radians.sin_cos();
+        (x * cos - y * sin, x * sin + y * cos)
+    }
+}
+
+//-------------------------------------------------------------------
+// Sign
+//-------------------------------------------------------------------
+
+impl<F: Float> AngleUnbounded<F> {
+    /// Returns the absolute value of the angle, i.e. the amount of rotation regardless of its direction.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// let a = AngleUnbounded::from_turns(-2.5_f32);
+    ///
+    /// assert_eq!(a.abs(), AngleUnbounded::from_turns(2.5));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self::from_radians(self.radians.abs())
+    }
+
+    /// Returns a number that represents the direction of the rotation.
+    ///
+    /// - `1.0` if the angle is positive (counterclockwise), `+0.0` or `+∞`
+    /// - `-1.0` if the angle is negative (clockwise), `-0.0` or `-∞`
+    /// - `NaN` if the angle is `NaN`
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn signum(self) -> F {
+        self.radians.signum()
+    }
+}
+
+//-------------------------------------------------------------------
+// Related angles
+//-------------------------------------------------------------------
+
+impl<F: Float> AngleUnbounded<F> {
+    /// Returns the opposite angle, i.e. `self + π`.
+    ///
+    /// Not to be confused with the negation `-self`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn opposite(self) -> Self {
+        self + Self::RAD_PI
+    }
+
+    /// Returns the complementary angle, i.e. `π/2 - self`.
+    ///
+    /// Unlike [`Angle::complement`], the result is not wrapped.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn complement(self) -> Self {
+        Self::RAD_FRAC_PI_2 - self
+    }
+
+    /// Returns the supplementary angle, i.e. `π - self`.
+    ///
+    /// Unlike [`Angle::supplement`], the result is not wrapped.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn supplement(self) -> Self {
+        Self::RAD_PI - self
+    }
+
+    /// Returns the angle mirrored across the line at the angle `axis`, i.e. `2 * axis - self`.
+    ///
+    /// Unlike [`Angle::reflect`], the result is not wrapped.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn reflect(self, axis: Self) -> Self {
+        axis + (axis - self)
+    }
+
+    /// Returns twice the angle, i.e. `self + self`.
+    ///
+    /// Unlike [`Angle::double`], the result is not wrapped.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn double(self) -> Self {
+        self + self
+    }
+
+    /// Returns half the angle.
+    ///
+    /// Since the number of turns is preserved, halving is not ambiguous, unlike [`Angle::half`].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn half(self) -> Self {
+        Self::from_radians(self.radians / (F::ONE + F::ONE))
+    }
+
+    /// Returns `true` if the angle is reflex, i.e. its magnitude is in `(π, 2π)`.
+    ///
+    /// The sign of the angle is ignored, so `-190°` is reflex. Angles within
+    /// [`Angle::EPSILON`] of a straight angle or a full turn are not reflex.
+    /// A rotation of more than a full turn is not reflex either.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// assert!(AngleUnbounded::from_degrees(190.0_f32).is_reflex());
+    /// assert!(!AngleUnbounded::from_degrees(170.0_f32).is_reflex());
+    /// assert!(!AngleUnbounded::from_degrees(550.0_f32).is_reflex());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn is_reflex(self) -> bool {
+        let magnitude = self.radians.abs();
+        F::PI + F::DOUBLE_EPSILON < magnitude && magnitude < F::TAU - F::DOUBLE_EPSILON
+    }
+
+    /// Returns the explementary angle, i.e. `2π - self`: the amount of rotation
+    /// to go the other way around to the same direction.
+    ///
+    /// The result is not wrapped: it is negative for angles of more than a full turn,
+    /// and more than a full turn for negative angles.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// # use float_eq::assert_float_eq;
+    /// let a = AngleUnbounded::from_degrees(190.0_f32);
+    ///
+    /// assert_float_eq!(a.reflex_complement().to_degrees(), 170.0, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn reflex_complement(self) -> Self {
+        Self::FULL_TURN - self
+    }
+}
+
+//-------------------------------------------------------------------
+// Comparison
+//-------------------------------------------------------------------
+
+impl<F: Float> AngleUnbounded<F> {
+    /// Returns `true` if the difference between `self` and `other` is not greater than `tolerance`.
+    ///
+    /// Unlike [`Angle::approx_eq`], the raw values are compared, so angles that differ
+    /// by a full turn are not approximately equal.
+    /// The sign of `tolerance` is ignored.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// let a = AngleUnbounded::from_degrees(90.0_f32);
+    ///
+    /// assert!(a.approx_eq(AngleUnbounded::from_degrees(90.1), AngleUnbounded::from_degrees(0.5)));
+    /// assert!(!a.approx_eq(AngleUnbounded::from_degrees(450.0), AngleUnbounded::from_degrees(0.5)));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn approx_eq(self, other: Self, tolerance: Self) -> bool {
+        (self.radians - other.radians).abs() <= tolerance.radians.abs()
+    }
+
+    /// Restricts the angle to the interval `[min, max]`.
+    ///
+    /// Unlike [`Angle::clamp`], the raw values are compared, so the bounds can be
+    /// several turns apart. If the angle is `NaN`, `NaN` is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, `min` is `NaN`, or `max` is `NaN`.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// let min = AngleUnbounded::from_turns(0.0_f32);
+    /// let max = AngleUnbounded::from_turns(3.0_f32);
+    ///
+    /// assert_eq!(AngleUnbounded::from_turns(5.0).clamp(min, max), max);
+    /// assert_eq!(AngleUnbounded::from_turns(-1.0).clamp(min, max), min);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.radians <= max.radians, "min > max, or either was NaN");
+        if self.radians < min.radians {
+            min
+        } else if self.radians > max.radians {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of the two angles.
+    ///
+    /// As for [`f32::min`], if one of the angles is `NaN`, the other one is returned.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// let a = AngleUnbounded::from_turns(1.5_f32);
+    /// let b = AngleUnbounded::from_turns(-0.5_f32);
+    ///
+    /// assert_eq!(a.min(b), b);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        if self.radians.is_nan() || other.radians < self.radians {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of the two angles.
+    ///
+    /// As for [`f32::max`], if one of the angles is `NaN`, the other one is returned.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// let a = AngleUnbounded::from_turns(1.5_f32);
+    /// let b = AngleUnbounded::from_turns(-0.5_f32);
+    ///
+    /// assert_eq!(a.max(b), a);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        if self.radians.is_nan() || other.radians > self.radians {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+impl<F: crate::float::FloatMath> AngleUnbounded<F> {
+    /// Computes `self * mul + add` with only one rounding error, yielding a more accurate
+    /// result than an unfused multiply-add.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// # use float_eq::assert_float_eq;
+    /// let a = AngleUnbounded::from_turns(0.25_f32);
+    ///
+    /// let b = a.mul_add(6.0, AngleUnbounded::FULL_TURN);
+    /// assert_float_eq!(b.to_turns(), 2.5, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn mul_add(self, mul: F, add: Self) -> Self {
+        Self::from_radians(self.radians.mul_add(mul, add.radians))
+    }
+}
+
+//-------------------------------------------------------------------
+// Interpolation
+//-------------------------------------------------------------------
+
+impl<F: Float> AngleUnbounded<F> {
+    /// Linearly interpolates between `self` and `other`.
+    ///
+    /// Unlike [`Angle::lerp`], the number of turns is preserved, so the interpolation
+    /// does not take the shortest arc.
+    ///
+    /// `t = 0` returns `self` and `t = 1` returns `other`, values outside of `[0, 1]`
+    /// extrapolate along the same direction.
+    ///
+    /// ```
+    /// # use angulus::AngleUnbounded;
+    /// # use float_eq::assert_float_eq;
+    /// let a = AngleUnbounded::from_turns(0.0_f32);
+    /// let b = AngleUnbounded::from_turns(3.0_f32);
+    ///
+    /// assert_float_eq!(a.lerp(b, 0.5).to_turns(), 1.5, abs <= 0.0001);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn lerp(self, other: Self, t: F) -> Self {
+        Self::from_radians(self.radians + (other.radians - self.radians) * t)
+    }
+}
+
+//-------------------------------------------------------------------
+// Range iteration
+//-------------------------------------------------------------------
+
+/// Ranges of unbounded angles step by one degree.
+///
+/// The length of a range is only known when its end is a whole number of steps away
+/// from its start, otherwise [`Iterator::count`] on the range panics.
+///
+/// ```
+/// #![feature(step_trait)]
+/// # use angulus::AngleUnbounded64;
+/// let angles: Vec<_> = (AngleUnbounded64::ZERO..AngleUnbounded64::DEG_90)
+///     .step_by(30)
+///     .map(|a| a.to_degrees().round())
+///     .collect();
+///
+/// assert_eq!(angles, [0.0, 30.0, 60.0]);
+/// ```
+#[cfg(all(feature = "step_trait", angulus_nightly))]
+impl<F: Float> core::iter::Step for AngleUnbounded<F> {
+    /// Returns `(n, Some(n))` if `end` is exactly `n` one degree steps away from `start`,
+    /// i.e. if `forward_checked(start, n)` is `end`.
+    ///
+    /// Otherwise, `n` is the difference in degrees rounded up and the upper bound is `None`:
+    /// because of the rounding of the repeated additions, a range may yield one angle
+    /// more or less than this count when its length is close to a whole number of degrees.
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        let degrees = (*end - *start).to_degrees();
+        if degrees.is_nan() || degrees < F::ZERO {
+            return (0, None);
+        }
+        let whole = match usize::try_from(degrees.trunc_to_i64()) {
+            Ok(whole) if whole < usize::MAX => whole,
+            _ => return (usize::MAX, None),
+        };
+
+        // The difference in degrees may be rounded across an integer.
+        for steps in [whole, whole + 1] {
+            if Self::forward_checked(*start, steps) == Some(*end) {
+                return (steps, Some(steps));
+            }
+        }
+
+        if F::from_u64(whole as u64) < degrees {
+            (whole + 1, None)
+        } else {
+            (whole, None)
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        let angle = start + Self::from_degrees(F::from_u64(count as u64));
+        if angle.is_finite() {
+            Some(angle)
+        } else {
+            None
+        }
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        let angle = start - Self::from_degrees(F::from_u64(count as u64));
+        if angle.is_finite() {
+            Some(angle)
+        } else {
+            None
+        }
+    }
 }
 
 //-------------------------------------------------------------------
@@ -328,6 +943,52 @@ impl<F: Float> SubAssign for AngleUnbounded<F> {
 
 forward_ref_op_assign!(impl<F: Float> SubAssign, sub_assign for AngleUnbounded<F>, AngleUnbounded<F>);
 
+/// Adds an angle of the other type.
+///
+/// The result has the type of the left operand, so it is not wrapped.
+impl<F: Float> Add<Angle<F>> for AngleUnbounded<F> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Angle<F>) -> Self::Output {
+        Self::from_radians(self.radians + rhs.to_radians())
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Add, add for AngleUnbounded<F>, Angle<F>);
+
+impl<F: Float> AddAssign<Angle<F>> for AngleUnbounded<F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Angle<F>) {
+        *self = *self + rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> AddAssign, add_assign for AngleUnbounded<F>, Angle<F>);
+
+/// Subtracts an angle of the other type.
+///
+/// The result has the type of the left operand, so it is not wrapped.
+impl<F: Float> Sub<Angle<F>> for AngleUnbounded<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Angle<F>) -> Self::Output {
+        Self::from_radians(self.radians - rhs.to_radians())
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Sub, sub for AngleUnbounded<F>, Angle<F>);
+
+impl<F: Float> SubAssign<Angle<F>> for AngleUnbounded<F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Angle<F>) {
+        *self = *self - rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> SubAssign, sub_assign for AngleUnbounded<F>, Angle<F>);
+
 impl<F: Float> Mul<F> for AngleUnbounded<F> {
     type Output = Self;
 
@@ -370,6 +1031,68 @@ impl<F: Float> MulAssign<F> for AngleUnbounded<F> {
 
 forward_ref_op_assign!(impl<F: Float> MulAssign, mul_assign for AngleUnbounded<F>, F);
 
+impl<F: Float> Mul<i32> for AngleUnbounded<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        self * F::from_i64(i64::from(rhs))
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for AngleUnbounded<F>, i32);
+
+impl<F: Float> Mul<AngleUnbounded<F>> for i32 {
+    type Output = AngleUnbounded<F>;
+
+    #[inline]
+    fn mul(self, rhs: AngleUnbounded<F>) -> Self::Output {
+        rhs * self
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for i32, AngleUnbounded<F>);
+
+impl<F: Float> MulAssign<i32> for AngleUnbounded<F> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> MulAssign, mul_assign for AngleUnbounded<F>, i32);
+
+impl<F: Float> Mul<u32> for AngleUnbounded<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> Self::Output {
+        self * F::from_u64(u64::from(rhs))
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for AngleUnbounded<F>, u32);
+
+impl<F: Float> Mul<AngleUnbounded<F>> for u32 {
+    type Output = AngleUnbounded<F>;
+
+    #[inline]
+    fn mul(self, rhs: AngleUnbounded<F>) -> Self::Output {
+        rhs * self
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Mul, mul for u32, AngleUnbounded<F>);
+
+impl<F: Float> MulAssign<u32> for AngleUnbounded<F> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32) {
+        *self = *self * rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> MulAssign, mul_assign for AngleUnbounded<F>, u32);
+
 impl<F: Float> Div<F> for AngleUnbounded<F> {
     type Output = Self;
 
@@ -390,6 +1113,37 @@ impl<F: Float> DivAssign<F> for AngleUnbounded<F> {
 
 forward_ref_op_assign!(impl<F: Float> DivAssign, div_assign for AngleUnbounded<F>, F);
 
+impl<F: Float> Div for AngleUnbounded<F> {
+    type Output = F;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.radians / rhs.radians
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Div, div for AngleUnbounded<F>, AngleUnbounded<F>);
+
+impl<F: Float> Rem<F> for AngleUnbounded<F> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: F) -> Self::Output {
+        Self::from_radians(self.radians % rhs)
+    }
+}
+
+forward_ref_binop!(impl<F: Float> Rem, rem for AngleUnbounded<F>, F);
+
+impl<F: Float> RemAssign<F> for AngleUnbounded<F> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: F) {
+        *self = *self % rhs;
+    }
+}
+
+forward_ref_op_assign!(impl<F: Float> RemAssign, rem_assign for AngleUnbounded<F>, F);
+
 impl<F: Float> Neg for AngleUnbounded<F> {
     type Output = Self;
 
@@ -407,11 +1161,148 @@ impl<F: Sum> Sum for AngleUnbounded<F> {
     }
 }
 
+impl<F: Sum> FromIterator<AngleUnbounded<F>> for AngleUnbounded<F> {
+    /// Collects the angles into their sum.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = AngleUnbounded<F>>>(iter: I) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use float_eq::assert_float_eq;
 
-    use crate::AngleUnbounded32;
+    use crate::{Angle32, AngleUnbounded32, AngleUnbounded64};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn angle_unbounded_signed_zero_hash() {
+        use std::collections::HashMap;
+
+        let key = |angle: AngleUnbounded64| angle.canonicalize().to_radians().to_bits();
+
+        let mut map = HashMap::new();
+        map.insert(key(AngleUnbounded64::from_radians(0.0)), "zero");
+        map.insert(key(AngleUnbounded64::from_radians(-0.0)), "negative zero");
+        map.insert(key(AngleUnbounded64::from_turns(1.0)), "full turn");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&key(AngleUnbounded64::ZERO)], "negative zero");
+
+        assert!((-AngleUnbounded64::ZERO).canonicalize().is_sign_positive());
+        assert!(AngleUnbounded64::from_radians(f64::NAN)
+            .canonicalize()
+            .is_nan());
+    }
+
+    #[test]
+    fn angle_unbounded_cast() {
+        for x in [0.0, 1.0, -2.5, 1e10] {
+            let a = AngleUnbounded64::from_radians(x);
+            assert_eq!(a.cast::<f32>(), a.to_f32());
+            assert_eq!(a.cast::<f64>(), a);
+
+            let a = a.to_f32();
+            assert_eq!(a.cast::<f64>(), a.to_f64());
+        }
+    }
+
+    #[test]
+    fn angle_unbounded_golden() {
+        assert_float_eq!(AngleUnbounded32::GOLDEN.to_degrees(), 137.5077, abs <= 1e-4);
+    }
+
+    #[test]
+    fn angle_unbounded_small_fraction_constants() {
+        assert_float_eq!(AngleUnbounded32::DEG_15.to_degrees(), 15.0, abs <= 1e-5);
+        assert_float_eq!(AngleUnbounded32::DEG_11_25.to_degrees(), 11.25, abs <= 1e-5);
+        assert_float_eq!(
+            AngleUnbounded64::RAD_FRAC_PI_12.to_degrees(),
+            15.0,
+            abs <= 1e-12
+        );
+        assert_float_eq!(
+            AngleUnbounded64::RAD_FRAC_PI_16.to_degrees(),
+            11.25,
+            abs <= 1e-12
+        );
+    }
+
+    #[test]
+    fn angle_unbounded_add_sub_angle_does_not_wrap() {
+        let a = AngleUnbounded32::from_turns(2.5);
+        let b = Angle32::DEG_90;
+
+        let sum: AngleUnbounded32 = a + b;
+        assert_float_eq!(sum.to_turns(), 2.75, abs <= 1e-6);
+        let diff: AngleUnbounded32 = a - b;
+        assert_float_eq!(diff.to_turns(), 2.25, abs <= 1e-6);
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, sum);
+        c -= &b;
+        assert_float_eq!(c.to_turns(), 2.5, abs <= 1e-6);
+    }
+
+    #[test]
+    fn angle_unbounded_mul_integer() {
+        assert_eq!(AngleUnbounded32::SIXTH * 6, AngleUnbounded32::FULL_TURN);
+        // NOTE: `π/3 * 6` is one ulp above `τ` in `f64`.
+        assert_float_eq!(
+            (6_u32 * AngleUnbounded64::SIXTH).to_radians(),
+            AngleUnbounded64::FULL_TURN.to_radians(),
+            ulps <= 1
+        );
+        assert_eq!(
+            AngleUnbounded32::DEG_90 * -5_i32,
+            AngleUnbounded32::DEG_90 * -5.0
+        );
+
+        let mut angle = AngleUnbounded64::DEG_15;
+        angle *= 48_u32;
+        assert_eq!(angle, AngleUnbounded64::DEG_15 * 48.0);
+    }
+
+    #[test]
+    fn angle_unbounded_div_angle() {
+        assert_float_eq!(
+            AngleUnbounded32::DEG_90 / AngleUnbounded32::DEG_30,
+            3.0,
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            AngleUnbounded32::DEG_30 / AngleUnbounded32::DEG_90,
+            1.0 / 3.0,
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            -AngleUnbounded32::DEG_90 / AngleUnbounded32::DEG_30,
+            -3.0,
+            abs <= 1e-6
+        );
+        assert!((AngleUnbounded32::DEG_90 / AngleUnbounded32::ZERO).is_infinite());
+    }
+
+    #[test]
+    fn angle_unbounded_rem() {
+        let period = AngleUnbounded32::DEG_30.to_radians();
+
+        assert_float_eq!(
+            (AngleUnbounded32::from_degrees(100.0) % period).to_degrees(),
+            10.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            (AngleUnbounded32::from_degrees(-100.0) % period).to_degrees(),
+            -10.0,
+            abs <= 1e-4
+        );
+
+        let mut angle = AngleUnbounded32::from_degrees(100.0);
+        angle %= period;
+        assert_float_eq!(angle.to_degrees(), 10.0, abs <= 1e-4);
+    }
 
     #[test]
     fn angle_unbounded_sum_is_accurate() {
@@ -445,4 +1336,476 @@ mod tests {
 
         assert_float_eq!(sum.to_radians(), add.to_radians(), abs <= 1e-5);
     }
+
+    #[test]
+    fn angle_unbounded_collect_is_sum() {
+        let angles = [30.0, 100.0, -45.0, 170.0].map(AngleUnbounded32::from_degrees);
+
+        let collected: AngleUnbounded32 = angles.iter().copied().collect();
+        let add = angles.iter().fold(AngleUnbounded32::ZERO, |a, b| a + b);
+
+        assert_float_eq!(collected.to_radians(), add.to_radians(), abs <= 1e-5);
+    }
+
+    #[test]
+    fn angle_unbounded_turns_count() {
+        let a = AngleUnbounded32::from_turns(3.25);
+        assert_eq!(a.turns_count(), 3);
+        assert_float_eq!(a.principal().to_degrees(), 90.0, abs <= 1e-4);
+
+        let a = AngleUnbounded32::from_turns(-3.25);
+        assert_eq!(a.turns_count(), -3);
+        assert_float_eq!(a.principal().to_degrees(), -90.0, abs <= 1e-4);
+
+        let a = AngleUnbounded64::from_turns(0.75);
+        assert_eq!(a.turns_count(), 0);
+        assert_float_eq!(a.principal().to_degrees(), -90.0, abs <= 1e-9);
+
+        let a = AngleUnbounded64::from_turns(-0.5);
+        assert_eq!(a.turns_count(), 0);
+        assert_float_eq!(a.principal().to_degrees(), 180.0, abs <= 1e-9);
+
+        assert_eq!(AngleUnbounded64::from_turns(1e12).turns_count(), i32::MAX);
+        assert_eq!(AngleUnbounded64::from_turns(-1e12).turns_count(), i32::MIN);
+        assert_eq!(AngleUnbounded64::from_turns(1e300).turns_count(), i32::MAX);
+        assert_eq!(AngleUnbounded64::from_turns(-1e300).turns_count(), i32::MIN);
+        assert_eq!(AngleUnbounded32::from_radians(f32::NAN).turns_count(), 0);
+    }
+
+    #[test]
+    fn angle_unbounded_float_predicates() {
+        let zero = AngleUnbounded32::ZERO;
+        assert!(zero.is_finite());
+        assert!(!zero.is_nan());
+        assert!(zero.is_sign_positive());
+        assert!(!zero.is_sign_negative());
+        assert!(AngleUnbounded32::from_radians(-0.0).is_sign_negative());
+
+        let positive = AngleUnbounded32::from_turns(2.5);
+        assert!(positive.is_finite());
+        assert!(positive.is_sign_positive());
+        assert!(!positive.is_sign_negative());
+
+        let negative = AngleUnbounded32::from_turns(-2.5);
+        assert!(negative.is_finite());
+        assert!(!negative.is_sign_positive());
+        assert!(negative.is_sign_negative());
+
+        let nan = AngleUnbounded32::from_radians(f32::NAN);
+        assert!(nan.is_nan());
+        assert!(!nan.is_finite());
+
+        let infinity = AngleUnbounded32::from_radians(f32::NEG_INFINITY);
+        assert!(!infinity.is_nan());
+        assert!(!infinity.is_finite());
+        assert!(infinity.is_sign_negative());
+    }
+
+    #[test]
+    fn angle_unbounded_full_turn() {
+        assert_float_eq!(
+            AngleUnbounded32::FULL_TURN.to_radians(),
+            core::f32::consts::TAU,
+            ulps <= 0
+        );
+        assert_eq!(
+            AngleUnbounded64::from_turns(1.0),
+            AngleUnbounded64::FULL_TURN
+        );
+        assert_eq!(AngleUnbounded32::FULL_TURN.turns_count(), 1);
+        assert_eq!(AngleUnbounded32::FULL_TURN.to_bounded(), Angle32::ZERO);
+    }
+
+    #[test]
+    fn angle_unbounded_integer_turns_are_exact() {
+        assert_eq!(AngleUnbounded32::from_turns(3.0).turns_count(), 3);
+        assert_eq!(AngleUnbounded64::from_turns(3.0).turns_count(), 3);
+
+        for n in -20_000_i32..=20_000 {
+            #[allow(clippy::cast_precision_loss)]
+            let turns = n as f32;
+            assert_eq!(AngleUnbounded32::from_turns(turns).turns_count(), n);
+            assert_eq!(AngleUnbounded64::from_turns(f64::from(n)).turns_count(), n);
+            assert_eq!(
+                AngleUnbounded64::from_turns(f64::from(n) + 0.5 * f64::from(n.signum()))
+                    .turns_count(),
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn angle_unbounded_abs_signum() {
+        assert_eq!(
+            AngleUnbounded32::from_turns(-2.5).abs(),
+            AngleUnbounded32::from_turns(2.5)
+        );
+        assert_eq!(
+            AngleUnbounded64::from_turns(2.5).abs(),
+            AngleUnbounded64::from_turns(2.5)
+        );
+        assert_float_eq!(
+            AngleUnbounded32::from_radians(-0.0).abs().to_radians(),
+            0.0,
+            ulps <= 0
+        );
+
+        assert_float_eq!(AngleUnbounded32::from_turns(-2.5).signum(), -1.0, ulps <= 0);
+        assert_float_eq!(AngleUnbounded64::from_turns(2.5).signum(), 1.0, ulps <= 0);
+        assert_float_eq!(AngleUnbounded32::ZERO.signum(), 1.0, ulps <= 0);
+        assert_float_eq!(
+            AngleUnbounded64::from_radians(f64::NEG_INFINITY).signum(),
+            -1.0,
+            ulps <= 0
+        );
+        assert!(AngleUnbounded32::from_radians(f32::NAN).signum().is_nan());
+    }
+
+    #[test]
+    fn angle_unbounded_related_angles() {
+        assert_float_eq!(
+            AngleUnbounded32::DEG_30.complement().to_radians(),
+            AngleUnbounded32::DEG_60.to_radians(),
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            AngleUnbounded32::DEG_30.supplement().to_radians(),
+            AngleUnbounded32::from_degrees(150.0).to_radians(),
+            abs <= 1e-6
+        );
+
+        // Not wrapped.
+        assert_float_eq!(
+            AngleUnbounded32::DEG_180.opposite().to_degrees(),
+            360.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            AngleUnbounded32::DEG_180.complement().to_degrees(),
+            -90.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            AngleUnbounded32::from_degrees(270.0)
+                .supplement()
+                .to_degrees(),
+            -90.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[test]
+    fn angle_unbounded_reflect() {
+        let a = AngleUnbounded32::from_degrees(30.0);
+        assert_eq!(a.reflect(AngleUnbounded32::ZERO), -a);
+
+        assert_float_eq!(
+            AngleUnbounded32::ZERO
+                .reflect(AngleUnbounded32::DEG_45)
+                .to_degrees(),
+            90.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            AngleUnbounded32::DEG_90
+                .reflect(AngleUnbounded32::DEG_45)
+                .to_degrees(),
+            0.0,
+            abs <= 1e-4
+        );
+
+        // Not wrapped.
+        assert_float_eq!(
+            AngleUnbounded32::from_degrees(-170.0)
+                .reflect(AngleUnbounded32::from_degrees(170.0))
+                .to_degrees(),
+            510.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[test]
+    fn angle_unbounded_half_double() {
+        assert_eq!(
+            AngleUnbounded32::DEG_180.double(),
+            AngleUnbounded32::FULL_TURN
+        );
+        assert_eq!(
+            AngleUnbounded32::FULL_TURN.half(),
+            AngleUnbounded32::DEG_180
+        );
+        assert_float_eq!(
+            AngleUnbounded32::from_degrees(350.0).half().to_degrees(),
+            175.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            AngleUnbounded32::from_degrees(-10.0).half().to_degrees(),
+            -5.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            AngleUnbounded32::from_degrees(300.0).double().to_degrees(),
+            600.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[test]
+    fn angle_unbounded_reflex() {
+        assert!(AngleUnbounded32::from_degrees(190.0).is_reflex());
+        assert!(AngleUnbounded32::from_degrees(-190.0).is_reflex());
+        assert!(AngleUnbounded32::from_degrees(359.0).is_reflex());
+        assert!(!AngleUnbounded32::from_degrees(170.0).is_reflex());
+        assert!(!AngleUnbounded32::DEG_180.is_reflex());
+        assert!(!AngleUnbounded32::FULL_TURN.is_reflex());
+        assert!(!AngleUnbounded32::from_degrees(550.0).is_reflex());
+        assert!(!AngleUnbounded32::from_radians(f32::NAN).is_reflex());
+
+        assert_float_eq!(
+            AngleUnbounded32::from_degrees(190.0)
+                .reflex_complement()
+                .to_degrees(),
+            170.0,
+            abs <= 1e-4
+        );
+        assert_float_eq!(
+            AngleUnbounded32::from_degrees(170.0)
+                .reflex_complement()
+                .to_degrees(),
+            190.0,
+            abs <= 1e-4
+        );
+        assert_eq!(
+            AngleUnbounded32::ZERO.reflex_complement(),
+            AngleUnbounded32::FULL_TURN
+        );
+        // Not wrapped.
+        assert_float_eq!(
+            AngleUnbounded32::from_degrees(450.0)
+                .reflex_complement()
+                .to_degrees(),
+            -90.0,
+            abs <= 1e-4
+        );
+    }
+
+    #[cfg(all(feature = "step_trait", angulus_nightly))]
+    #[test]
+    fn angle_unbounded_range() {
+        use core::iter::Step;
+
+        let start = AngleUnbounded64::ZERO;
+        let end = AngleUnbounded64::from_degrees(10.0);
+        assert_eq!((start..end).count(), 10);
+        assert_eq!((start..=end).count(), 11);
+        assert_eq!((start..end).step_by(4).count(), 3);
+        assert_eq!((end..start).count(), 0);
+
+        assert_eq!(Step::steps_between(&start, &end), (10, Some(10)));
+        assert_eq!(Step::forward_checked(start, 10), Some(end));
+
+        let end = AngleUnbounded64::from_degrees(10.5);
+        assert_eq!((start..end).fold(0, |count, _| count + 1), 11);
+        assert_eq!(Step::steps_between(&start, &end), (11, None));
+        assert_eq!(Step::steps_between(&end, &start), (0, None));
+
+        // The difference in degrees is rounded above 90, but the end is 90 steps away.
+        let end = AngleUnbounded64::DEG_90;
+        let (steps, exact) = Step::steps_between(&start, &end);
+        assert_eq!(steps, 90);
+        assert_eq!(
+            exact.is_some(),
+            Step::forward_checked(start, 90) == Some(end)
+        );
+
+        let angles: Vec<_> = (AngleUnbounded64::from_degrees(-2.0)..AngleUnbounded64::ZERO)
+            .map(AngleUnbounded64::to_degrees)
+            .collect();
+        assert_float_eq!(angles[0], -2.0, abs <= 1e-9);
+        assert_float_eq!(angles[1], -1.0, abs <= 1e-9);
+        assert_eq!(angles.len(), 2);
+
+        assert_eq!(
+            Step::forward_checked(AngleUnbounded64::from_radians(f64::INFINITY), 1),
+            None
+        );
+    }
+
+    #[test]
+    fn angle_unbounded_clamp() {
+        let min = AngleUnbounded32::from_turns(0.0);
+        let max = AngleUnbounded32::from_turns(3.0);
+
+        assert_eq!(AngleUnbounded32::from_turns(5.0).clamp(min, max), max);
+        assert_eq!(AngleUnbounded32::from_turns(-0.5).clamp(min, max), min);
+        assert_eq!(
+            AngleUnbounded32::from_turns(2.5).clamp(min, max),
+            AngleUnbounded32::from_turns(2.5)
+        );
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(min.clamp(min, min), min);
+        assert!(AngleUnbounded32::from_radians(f32::NAN)
+            .clamp(min, max)
+            .to_radians()
+            .is_nan());
+    }
+
+    #[test]
+    #[should_panic = "min > max"]
+    fn angle_unbounded_clamp_min_greater_than_max() {
+        let _ = AngleUnbounded32::ZERO.clamp(AngleUnbounded32::DEG_90, AngleUnbounded32::ZERO);
+    }
+
+    #[test]
+    #[should_panic = "min > max, or either was NaN"]
+    fn angle_unbounded_clamp_nan_bound() {
+        let _ = AngleUnbounded32::ZERO.clamp(
+            AngleUnbounded32::from_radians(f32::NAN),
+            AngleUnbounded32::ZERO,
+        );
+    }
+
+    #[test]
+    fn angle_unbounded_min_max() {
+        let a = AngleUnbounded32::from_turns(1.5);
+        let b = AngleUnbounded32::from_turns(-0.5);
+
+        assert_eq!(a.min(b), b);
+        assert_eq!(b.min(a), b);
+        assert_eq!(a.max(b), a);
+        assert_eq!(b.max(a), a);
+        assert_eq!(a.min(a), a);
+
+        // NaN is ignored.
+        let nan = AngleUnbounded32::from_radians(f32::NAN);
+        assert_eq!(a.min(nan), a);
+        assert_eq!(nan.min(a), a);
+        assert_eq!(a.max(nan), a);
+        assert_eq!(nan.max(a), a);
+        assert!(nan.min(nan).to_radians().is_nan());
+        assert!(nan.max(nan).to_radians().is_nan());
+    }
+
+    #[test]
+    fn angle_unbounded_approx_eq() {
+        let tolerance = AngleUnbounded32::from_degrees(0.001);
+        assert!(
+            AngleUnbounded32::DEG_90.approx_eq(AngleUnbounded32::from_degrees(90.0005), tolerance)
+        );
+        assert!(
+            AngleUnbounded32::DEG_90.approx_eq(AngleUnbounded32::from_degrees(90.0005), -tolerance)
+        );
+        assert!(
+            !AngleUnbounded32::DEG_90.approx_eq(AngleUnbounded32::from_degrees(450.0), tolerance)
+        );
+        assert!(!AngleUnbounded32::from_degrees(179.9998)
+            .approx_eq(AngleUnbounded32::from_degrees(-179.9998), tolerance));
+
+        let tolerance = AngleUnbounded32::from_degrees(0.5);
+        assert!(
+            !AngleUnbounded32::DEG_90.approx_eq(AngleUnbounded32::from_degrees(91.0), tolerance)
+        );
+    }
+
+    #[test]
+    fn angle_unbounded_lerp_preserves_turns() {
+        let a = AngleUnbounded32::from_turns(0.0);
+        let b = AngleUnbounded32::from_turns(3.0);
+
+        assert_float_eq!(a.lerp(b, 0.0).to_turns(), 0.0, abs <= 1e-5);
+        assert_float_eq!(a.lerp(b, 0.5).to_turns(), 1.5, abs <= 1e-5);
+        assert_float_eq!(a.lerp(b, 1.0).to_turns(), 3.0, abs <= 1e-5);
+        assert_float_eq!(a.lerp(b, 2.0).to_turns(), 6.0, abs <= 1e-5);
+        assert_float_eq!(b.lerp(a, 0.25).to_turns(), 2.25, abs <= 1e-5);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_unbounded_mul_add() {
+        let a = AngleUnbounded64::from_radians(0.1);
+        let b = AngleUnbounded64::from_radians(-1.0);
+        assert_float_eq!(
+            a.mul_add(10.0, b).to_radians(),
+            (a * 10.0 + b).to_radians(),
+            abs <= 1e-15
+        );
+        assert_float_eq!(
+            a.mul_add(10.0, b).to_radians(),
+            5.551_115_123_125_783e-17,
+            ulps <= 0
+        );
+
+        let a = AngleUnbounded32::from_turns(1.5);
+        let b = AngleUnbounded32::from_turns(-0.25);
+        assert_float_eq!(
+            a.mul_add(3.0, b).to_turns(),
+            (a * 3.0 + b).to_turns(),
+            abs <= 1e-6
+        );
+        assert_float_eq!(a.mul_add(3.0, b).to_turns(), 4.25, abs <= 1e-6);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_unbounded_reciprocal_trigonometry() {
+        let sqrt_2 = core::f32::consts::SQRT_2;
+
+        assert_float_eq!(AngleUnbounded32::DEG_45.sec(), sqrt_2, abs <= 1e-6);
+        assert_float_eq!(AngleUnbounded32::DEG_45.csc(), sqrt_2, abs <= 1e-6);
+        assert_float_eq!(AngleUnbounded32::DEG_45.cot(), 1.0, abs <= 1e-6);
+
+        assert!(AngleUnbounded32::ZERO.csc().is_infinite());
+        assert!(AngleUnbounded32::ZERO.cot().is_infinite());
+        assert!(AngleUnbounded32::DEG_90.sec().abs() > 1e6);
+        assert!(AngleUnbounded32::DEG_180.csc().abs() > 1e6);
+        assert_float_eq!(AngleUnbounded32::DEG_90.cot(), 0.0, abs <= 1e-6);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_unbounded_to_vector() {
+        macro_rules! check {
+            ($angle:expr, ($x:expr, $y:expr)) => {
+                let (x, y) = $angle.to_vector();
+                assert_float_eq!(x, $x, abs <= 1e-6);
+                assert_float_eq!(y, $y, abs <= 1e-6);
+            };
+        }
+
+        check!(AngleUnbounded32::ZERO, (1.0, 0.0));
+        check!(AngleUnbounded32::DEG_90, (0.0, 1.0));
+        check!(AngleUnbounded32::DEG_180, (-1.0, 0.0));
+        check!(-AngleUnbounded32::DEG_90, (0.0, -1.0));
+        check!(AngleUnbounded32::from_turns(2.25), (0.0, 1.0));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_unbounded_to_rotation_matrix() {
+        let [row_x, row_y] = AngleUnbounded32::DEG_90.to_rotation_matrix();
+        let vector = [1.0, 0.0];
+
+        let rotated_x = row_x[0] * vector[0] + row_x[1] * vector[1];
+        let rotated_y = row_y[0] * vector[0] + row_y[1] * vector[1];
+
+        assert_float_eq!(rotated_x, 0.0, abs <= 1e-6);
+        assert_float_eq!(rotated_y, 1.0, abs <= 1e-6);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "cordic"))]
+    #[test]
+    fn angle_unbounded_rotate_vector() {
+        macro_rules! check {
+            ($angle:expr, ($x:expr, $y:expr)) => {
+                let (x, y) = $angle.rotate_vector((1.0, 0.0));
+                assert_float_eq!(x, $x, abs <= 1e-6);
+                assert_float_eq!(y, $y, abs <= 1e-6);
+            };
+        }
+
+        check!(AngleUnbounded32::DEG_90, (0.0, 1.0));
+        check!(AngleUnbounded32::DEG_180, (-1.0, 0.0));
+        check!(-AngleUnbounded32::DEG_90, (0.0, -1.0));
+    }
 }
@@ -3,33 +3,170 @@
 //! Wrapping an [`Angle`] or an [`AngleUnbounded`] with these wrappers enables [`Display`] capability.
 //!
 //! ```
-//! # use angulus::{Angle, ToAngle, units::{Degrees, Radians, Turns, Gradians}};
+//! # use angulus::{Angle, ToAngle, units::{Arcminutes, Arcseconds, Degrees, Hours, Mils, Radians, Turns, Gradians}};
 //! let angle = 90.0_f32.deg();
 //!
 //! assert_eq!(format!("{}", Radians(angle)), "1.5707964 rad");
 //! assert_eq!(format!("{}", Degrees(angle)), "90°");
 //! assert_eq!(format!("{}", Turns(angle)), "0.25 tr");
 //! assert_eq!(format!("{}", Gradians(angle)), "100g");
+//! assert_eq!(format!("{}", Arcminutes(angle)), "5400'");
+//! assert_eq!(format!("{}", Arcseconds(angle)), "324000\"");
+//! assert_eq!(format!("{}", Mils(angle)), "1600 mil");
+//! assert_eq!(format!("{}", Hours(angle)), "6 h");
 //! ```
+//!
+//! The precision and the `+` flag of the formatter apply to the value.
+//!
+//! ```
+//! # use angulus::{ToAngle, units::{Degrees, Radians}};
+//! let angle = 90.0_f32.deg();
+//!
+//! assert_eq!(format!("{:.2}", Degrees(angle)), "90.00°");
+//! assert_eq!(format!("{:.3}", Radians(angle)), "1.571 rad");
+//! assert_eq!(format!("{:+}", Degrees(angle)), "+90°");
+//! ```
+//!
+//! The wrappers also implement [`FromStr`], parsing a value optionally followed by the symbol of the unit.
+//!
+//! ```
+//! # use angulus::units::Degrees;
+//! # use angulus::Angle32;
+//! let angle: Degrees<Angle32> = "90°".parse().unwrap();
+//! assert_eq!(angle.to_string(), "90°");
+//!
+//! assert!("90 rad".parse::<Degrees<Angle32>>().is_err());
+//! ```
+//!
+//! Since angles are stored in radians, the parsed value may differ from the input in its last digits.
+//!
+//! The arithmetic operators apply to the wrapped angles and keep the unit.
+//!
+//! ```
+//! # use angulus::{Angle32, units::Degrees};
+//! let angle = Degrees(Angle32::DEG_30) + Degrees(Angle32::DEG_60);
+//! assert_eq!(angle.to_string(), "90°");
+//! assert_eq!((-angle * 2.0).to_string(), "180°");
+//!
+//! let total: Degrees<Angle32> = [Degrees(Angle32::DEG_30), Degrees(Angle32::DEG_60)]
+//!     .into_iter()
+//!     .sum();
+//! assert_eq!(total.to_string(), "90°");
+//! ```
+//!
+//! The wrappers dereference to the wrapped angle, so its methods can be called directly.
+//!
+//! ```
+//! # use angulus::{Angle32, units::Degrees};
+//! let angle = Degrees(Angle32::DEG_90);
+//! assert_eq!(angle.to_turns(), 0.25);
+//! ```
+//!
+//! [`map`][Degrees::map] transforms the wrapped angle and keeps the unit.
+//!
+//! ```
+//! # use angulus::{Angle32, units::Degrees};
+//! let angle = Degrees(Angle32::DEG_30).map(|a| a.complement());
+//! assert_eq!(angle.to_string(), "60°");
+//! ```
+//!
+//! A wrapper can be converted into another one with [`From`], which only changes the unit
+//! used to display the angle, not its value.
+//!
+//! ```
+//! # use angulus::{Angle32, units::{Degrees, Turns}};
+//! let angle: Turns<Angle32> = Degrees(Angle32::DEG_90).into();
+//! assert_eq!(angle.to_string(), "0.25 tr");
+//! ```
+//!
+//! [`Gon`] is the same unit as [`Gradians`], written with the `gon` symbol used in surveying.
+//!
+//! ```
+//! # use angulus::{Angle32, units::Gon};
+//! let angle: Gon<Angle32> = "50 gon".parse().unwrap();
+//! assert_eq!(angle.to_string(), "50 gon");
+//! ```
+//!
+//! To choose the unit at runtime, use [`Angle::display_as`] with a [`Unit`].
+//!
+//! ```
+//! # use angulus::{Angle32, units::Unit};
+//! let angle = Angle32::DEG_90;
+//! assert_eq!(angle.display_as(Unit::Turns).to_string(), "0.25 tr");
+//! ```
+//!
+//! For a unit that is not provided by this module, define a [`CustomUnit`].
+//!
+//! To display an angle as degrees, minutes and seconds, use [`Dms`].
 
 use core::fmt::Display;
+use core::iter::Sum;
+use core::ops::{Add, Deref, Div, Mul, Neg, Sub};
+use core::str::FromStr;
 
 use crate::float::Float;
-use crate::{Angle, AngleUnbounded};
+use crate::parse::parse_with_symbol;
+use crate::{Angle, AngleUnbounded, ParseAngleError};
+
+/// Formats the value followed by the symbol of its unit.
+///
+/// The precision and the `+` flag of the formatter apply to the value.
+fn fmt_with_symbol<F: Display>(
+    value: F,
+    symbol: &str,
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+    match (f.sign_plus(), f.precision()) {
+        (false, None) => write!(f, "{value}{symbol}"),
+        (false, Some(precision)) => write!(f, "{value:.precision$}{symbol}"),
+        (true, None) => write!(f, "{value:+}{symbol}"),
+        (true, Some(precision)) => write!(f, "{value:+.precision$}{symbol}"),
+    }
+}
 
 macro_rules! unit {
     (
-        $Unit:ident, $doc:expr, $to_method:ident, $from_method:ident, $format:expr
+        $Unit:ident, $doc:expr, $to_method:ident, $from_method:ident, $symbol:expr
     ) => {
         /// Unit wrapper for the
         #[doc = $doc]
         /// unit.
         ///
         /// See the [module level documentation][self] for more details.
-        #[derive(Debug, Copy, Clone)]
+        #[derive(Debug, Copy, Clone, Default)]
         #[repr(transparent)]
         pub struct $Unit<A>(pub A);
 
+        impl<A> $Unit<A> {
+            /// Wraps the angle.
+            #[must_use]
+            #[inline]
+            pub const fn new(angle: A) -> Self {
+                Self(angle)
+            }
+
+            /// Returns a reference to the wrapped angle.
+            #[must_use]
+            #[inline]
+            pub const fn as_inner(&self) -> &A {
+                &self.0
+            }
+
+            /// Unwraps the angle.
+            #[must_use]
+            #[inline]
+            pub fn into_inner(self) -> A {
+                self.0
+            }
+
+            /// Transforms the wrapped angle with `f`, keeping the unit.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            #[inline]
+            pub fn map(self, f: impl FnOnce(A) -> A) -> Self {
+                Self(f(self.0))
+            }
+        }
+
         impl<F: Float> $Unit<Angle<F>> {
             /// Returns the value of the angle in the
             #[doc = $doc]
@@ -75,23 +212,835 @@ macro_rules! unit {
             }
         }
 
+        impl<A> Deref for $Unit<A> {
+            type Target = A;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<A> AsRef<A> for $Unit<A> {
+            #[inline]
+            fn as_ref(&self) -> &A {
+                &self.0
+            }
+        }
+
+        impl<A: Add<Output = A>> Add for $Unit<A> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl<A: Sub<Output = A>> Sub for $Unit<A> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl<A: Neg<Output = A>> Neg for $Unit<A> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+
+        impl<A: Mul<F, Output = A>, F> Mul<F> for $Unit<A> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: F) -> Self::Output {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl<A: Div<F, Output = A>, F> Div<F> for $Unit<A> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: F) -> Self::Output {
+                Self(self.0 / rhs)
+            }
+        }
+
+        impl<A: Sum> Sum for $Unit<A> {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Self(iter.map(|x| x.0).sum())
+            }
+        }
+
         impl<F: Float + Display> Display for $Unit<Angle<F>> {
             #[inline]
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, $format, self.to_value())
+                fmt_with_symbol(self.to_value(), $symbol, f)
             }
         }
 
         impl<F: Float + Display> Display for $Unit<AngleUnbounded<F>> {
             #[inline]
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, $format, self.to_value())
+                fmt_with_symbol(self.to_value(), $symbol, f)
+            }
+        }
+
+        impl<F: Float + FromStr> FromStr for $Unit<Angle<F>> {
+            type Err = ParseAngleError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_with_symbol(s, $symbol).map(Self::from_value)
+            }
+        }
+
+        impl<F: Float + FromStr> FromStr for $Unit<AngleUnbounded<F>> {
+            type Err = ParseAngleError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_with_symbol(s, $symbol).map(Self::from_value)
             }
         }
     };
 }
 
-unit!(Radians, "radian", to_radians, from_radians, "{} rad");
-unit!(Degrees, "degree", to_degrees, from_degrees, "{}°");
-unit!(Turns, "turn", to_turns, from_turns, "{} tr");
-unit!(Gradians, "gradian", to_gradians, from_gradians, "{}g");
+unit!(Radians, "radian", to_radians, from_radians, " rad");
+unit!(Degrees, "degree", to_degrees, from_degrees, "°");
+unit!(Turns, "turn", to_turns, from_turns, " tr");
+unit!(Gradians, "gradian", to_gradians, from_gradians, "g");
+unit!(Gon, "gon (gradian)", to_gradians, from_gradians, " gon");
+unit!(Arcminutes, "arcminute", to_arcminutes, from_arcminutes, "'");
+unit!(
+    Arcseconds,
+    "arcsecond",
+    to_arcseconds,
+    from_arcseconds,
+    "\""
+);
+unit!(Mils, "NATO mil", to_mils, from_mils, " mil");
+unit!(Hours, "hour", to_hours, from_hours, " h");
+
+/// Implements the conversions between each pair of unit wrappers.
+macro_rules! unit_conversions {
+    () => {};
+    ($Unit:ident $(, $Other:ident)*) => {
+        $(
+            impl<A> From<$Unit<A>> for $Other<A> {
+                #[inline]
+                fn from(x: $Unit<A>) -> Self {
+                    Self(x.0)
+                }
+            }
+
+            impl<A> From<$Other<A>> for $Unit<A> {
+                #[inline]
+                fn from(x: $Other<A>) -> Self {
+                    Self(x.0)
+                }
+            }
+        )*
+
+        unit_conversions!($($Other),*);
+    };
+}
+
+unit_conversions!(Radians, Degrees, Turns, Gradians, Gon, Arcminutes, Arcseconds, Mils, Hours);
+
+//-------------------------------------------------------------------
+// Runtime unit
+//-------------------------------------------------------------------
+
+/// A unit chosen at runtime to display an angle, see [`Angle::display_as`].
+///
+/// These are also the units recognized when parsing an angle from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// Displays the angle like [`Radians`].
+    Radians,
+    /// Displays the angle like [`Degrees`].
+    Degrees,
+    /// Displays the angle like [`Turns`].
+    Turns,
+    /// Displays the angle like [`Gradians`].
+    Gradians,
+}
+
+/// Displays an angle with a [`Unit`] chosen at runtime.
+pub(crate) struct DisplayAs<F> {
+    pub(crate) angle: Angle<F>,
+    pub(crate) unit: Unit,
+}
+
+impl<F: Float + Display> Display for DisplayAs<F> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.unit {
+            Unit::Radians => Radians(self.angle).fmt(f),
+            Unit::Degrees => Degrees(self.angle).fmt(f),
+            Unit::Turns => Turns(self.angle).fmt(f),
+            Unit::Gradians => Gradians(self.angle).fmt(f),
+        }
+    }
+}
+
+//-------------------------------------------------------------------
+// Custom unit
+//-------------------------------------------------------------------
+
+/// A unit defined at runtime by its symbol and its number of units in a full turn.
+///
+/// ```
+/// # use angulus::{Angle32, units::CustomUnit};
+/// # use float_eq::assert_float_eq;
+/// let clicks = CustomUnit::new(" clicks", 400.0_f32);
+///
+/// assert_float_eq!(clicks.to_value(Angle32::DEG_90), 100.0, abs <= 0.0001);
+/// assert_eq!(clicks.display(Angle32::DEG_90).to_string(), "100 clicks");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomUnit<F> {
+    /// The symbol displayed after the value.
+    pub symbol: &'static str,
+    /// The number of units in a full turn.
+    pub per_turn: F,
+}
+
+impl<F> CustomUnit<F> {
+    /// Creates a new unit from its symbol and its number of units in a full turn.
+    #[must_use]
+    #[inline]
+    pub const fn new(symbol: &'static str, per_turn: F) -> Self {
+        Self { symbol, per_turn }
+    }
+}
+
+impl<F: Float> CustomUnit<F> {
+    /// Returns the value of the angle in this unit.
+    ///
+    /// The value is in [the main range](crate#the-main-range), i.e. `(-per_turn/2, per_turn/2]`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn to_value(&self, angle: Angle<F>) -> F {
+        angle.to_turns() * self.per_turn
+    }
+
+    /// Converts a value in this unit into an angle.
+    #[must_use]
+    #[inline]
+    pub fn from_value(&self, x: F) -> Angle<F> {
+        Angle::from_turns(x / self.per_turn)
+    }
+
+    /// Returns an object that displays the angle in this unit, i.e. its value followed by the symbol.
+    ///
+    /// Like the other unit wrappers, the precision and the `+` flag of the formatter apply to the value.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    #[inline]
+    pub fn display(&self, angle: Angle<F>) -> impl Display
+    where
+        F: Display,
+    {
+        CustomDisplay { unit: *self, angle }
+    }
+}
+
+/// Displays an angle with a [`CustomUnit`].
+struct CustomDisplay<F> {
+    unit: CustomUnit<F>,
+    angle: Angle<F>,
+}
+
+impl<F: Float + Display> Display for CustomDisplay<F> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_with_symbol(self.unit.to_value(self.angle), self.unit.symbol, f)
+    }
+}
+
+//-------------------------------------------------------------------
+// Degrees, minutes and seconds
+//-------------------------------------------------------------------
+
+/// An angle decomposed into whole degrees, whole minutes and fractional seconds.
+///
+/// The components hold the magnitude of the angle, its sign is stored separately in `negative`.
+///
+/// ```
+/// # use angulus::{Angle, units::Dms};
+/// let dms = Dms::from_angle(Angle::from_degrees(-12.5_f32));
+///
+/// assert_eq!(dms.degrees, 12);
+/// assert_eq!(dms.minutes, 30);
+/// assert!(dms.negative);
+/// assert_eq!(format!("{dms}"), "-12°30'0\"");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Dms<F> {
+    /// The whole degrees.
+    pub degrees: i32,
+    /// The whole minutes, in the range `[0, 60)`.
+    pub minutes: u8,
+    /// The seconds, in the range `[0, 60)`.
+    pub seconds: F,
+    /// Whether the angle is negative.
+    pub negative: bool,
+}
+
+impl<F: Float> Dms<F> {
+    /// Decomposes an angle in [the main range](crate#the-main-range) into degrees, minutes and seconds.
+    #[must_use]
+    pub fn from_angle(angle: Angle<F>) -> Self {
+        let arcseconds = angle.to_arcseconds();
+        let negative = arcseconds < F::ZERO;
+        let arcseconds = if negative { -arcseconds } else { arcseconds };
+
+        // NOTE: the whole part is decomposed with integer arithmetic to avoid rounding errors.
+        let whole = arcseconds.trunc_to_i64();
+        let fract = arcseconds - F::from_i64(whole);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Self {
+            degrees: (whole / 3600) as i32,
+            minutes: ((whole % 3600) / 60) as u8,
+            seconds: F::from_i64(whole % 60) + fract,
+            negative,
+        }
+        .carry()
+    }
+
+    /// Recombines the degrees, minutes and seconds into an angle.
+    #[must_use]
+    pub fn to_angle(self) -> Angle<F> {
+        let whole = i64::from(self.degrees) * 3600 + i64::from(self.minutes) * 60;
+        let arcseconds = F::from_i64(whole) + self.seconds;
+        Angle::from_arcseconds(if self.negative {
+            -arcseconds
+        } else {
+            arcseconds
+        })
+    }
+
+    /// Rounds the seconds to the given number of decimal places, carrying
+    /// into the minutes and the degrees if the seconds are rounded up to 60.
+    ///
+    /// Decimal places above 15 are treated as 15.
+    ///
+    /// ```
+    /// # use angulus::{Angle, units::Dms};
+    /// let angle = Angle::from_degrees(12.0 + 34.0 / 60.0 + 59.9999 / 3600.0);
+    /// let dms = Dms::from_angle(angle).round_seconds(2);
+    ///
+    /// assert_eq!(dms.degrees, 12);
+    /// assert_eq!(dms.minutes, 35);
+    /// assert_eq!(dms.seconds, 0.0);
+    /// ```
+    #[must_use]
+    pub fn round_seconds(self, decimals: u32) -> Self {
+        let ten = F::from_u64(10);
+        let mut scale = F::ONE;
+        for _ in 0..decimals.min(15) {
+            scale *= ten;
+        }
+
+        let seconds = F::from_i64((self.seconds * scale).round_to_i64()) / scale;
+        Self { seconds, ..self }.carry()
+    }
+
+    /// Carries the seconds into the minutes and the minutes into the degrees.
+    fn carry(mut self) -> Self {
+        let sixty = F::from_u64(60);
+        if self.seconds >= sixty {
+            self.seconds -= sixty;
+            self.minutes += 1;
+        }
+        if self.minutes >= 60 {
+            self.minutes -= 60;
+            self.degrees += 1;
+        }
+        self
+    }
+}
+
+impl<F: Float> From<Angle<F>> for Dms<F> {
+    #[inline]
+    fn from(angle: Angle<F>) -> Self {
+        Self::from_angle(angle)
+    }
+}
+
+impl<F: Float> From<Dms<F>> for Angle<F> {
+    #[inline]
+    fn from(dms: Dms<F>) -> Self {
+        dms.to_angle()
+    }
+}
+
+impl<F: Float + Display> Display for Dms<F> {
+    /// Formats as `12°34'56.7"`.
+    ///
+    /// The precision of the formatter applies to the seconds, which are rounded accordingly.
+    /// Without precision, the seconds are rounded to 9 decimal places, without trailing zeros.
+    /// The `+` flag of the formatter prints the sign of positive angles.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.negative {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        if let Some(precision) = f.precision() {
+            let decimals = u32::try_from(precision).unwrap_or(u32::MAX);
+            let dms = self.round_seconds(decimals);
+            write!(
+                f,
+                "{sign}{}°{}'{:.*}\"",
+                dms.degrees, dms.minutes, precision, dms.seconds
+            )
+        } else {
+            // NOTE: the shortest representation of the rounded seconds has no trailing zeros.
+            let dms = self.round_seconds(9);
+            write!(f, "{sign}{}°{}'{}\"", dms.degrees, dms.minutes, dms.seconds)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use crate::units::{
+        Arcminutes, Arcseconds, CustomUnit, Degrees, Dms, Gon, Gradians, Hours, Mils, Radians,
+        Turns,
+    };
+    use crate::{Angle32, Angle64, AngleUnbounded32};
+
+    #[test]
+    fn units_default_and_new() {
+        #[derive(Default)]
+        struct Config {
+            heading: Degrees<Angle32>,
+            spin: Turns<AngleUnbounded32>,
+        }
+
+        const RIGHT: Degrees<Angle32> = Degrees::new(Angle32::DEG_90);
+
+        let config = Config::default();
+        assert_eq!(config.heading.0, Angle32::ZERO);
+        assert_eq!(config.spin.0, AngleUnbounded32::ZERO);
+
+        assert_eq!(RIGHT.0, Angle32::DEG_90);
+        assert_float_eq!(Gon::new(Angle64::DEG_45).to_value(), 50.0, abs <= 1e-12);
+        assert_eq!(Radians::<Angle64>::default().0, Angle64::ZERO);
+    }
+
+    #[test]
+    fn units_map_and_inner() {
+        let angle: Degrees<Angle32> =
+            Degrees(Angle32::DEG_30).map(|a| a + Angle32::from_degrees(10.0));
+        assert_float_eq!(angle.to_value(), 40.0, abs <= 1e-4);
+        assert_eq!(angle.as_inner(), &angle.0);
+        assert_eq!(angle.into_inner(), angle.0);
+
+        let turns = Turns(AngleUnbounded32::FULL_TURN).map(|a| a * 2.0);
+        assert_float_eq!(turns.to_value(), 2.0, abs <= 1e-6);
+        assert_eq!(turns.into_inner(), AngleUnbounded32::from_turns(2.0));
+    }
+
+    #[test]
+    fn units_deref_to_the_angle() {
+        let angle = Degrees(Angle32::DEG_90);
+        assert_float_eq!(angle.to_radians(), core::f32::consts::FRAC_PI_2, ulps <= 0);
+        assert_float_eq!(angle.to_turns(), 0.25, ulps <= 0);
+        assert!(angle.is_right());
+        assert_eq!(angle.angle_to(Angle32::ZERO), -Angle32::DEG_90);
+
+        let angle = Turns(AngleUnbounded32::from_turns(2.5));
+        assert_float_eq!(angle.to_degrees(), 900.0, abs <= 1e-4);
+        assert_eq!(angle.as_ref(), &angle.0);
+
+        // `.0` still works.
+        assert_eq!(angle.0, AngleUnbounded32::from_turns(2.5));
+    }
+
+    #[test]
+    fn units_conversions_preserve_the_angle() {
+        let degrees = Degrees(Angle32::from_degrees(123.0));
+
+        let turns: Turns<Angle32> = degrees.into();
+        assert_float_eq!(turns.0.to_radians(), degrees.0.to_radians(), ulps <= 0);
+
+        let radians: Radians<Angle32> = turns.into();
+        let hours: Hours<Angle32> = radians.into();
+        let back: Degrees<Angle32> = hours.into();
+        assert_float_eq!(back.0.to_radians(), degrees.0.to_radians(), ulps <= 0);
+
+        let mils: Mils<AngleUnbounded32> = Gradians(AngleUnbounded32::from_turns(2.0)).into();
+        assert_float_eq!(mils.to_value(), 12_800.0, abs <= 1e-2);
+        let arcminutes: Arcminutes<AngleUnbounded32> = mils.into();
+        let arcseconds: Arcseconds<AngleUnbounded32> = arcminutes.into();
+        assert_float_eq!(arcseconds.0.to_turns(), 2.0, ulps <= 0);
+    }
+
+    #[test]
+    fn units_ops_keep_the_unit() {
+        let sum: Degrees<Angle32> = Degrees(Angle32::DEG_30) + Degrees(Angle32::DEG_60);
+        assert_float_eq!(sum.to_value(), 90.0, abs <= 1e-4);
+
+        let diff = Degrees(Angle32::DEG_60) - Degrees(Angle32::DEG_90);
+        assert_float_eq!(diff.to_value(), -30.0, abs <= 1e-4);
+        assert_float_eq!((-diff).to_value(), 30.0, abs <= 1e-4);
+
+        let turns: Turns<AngleUnbounded32> = Turns(AngleUnbounded32::DEG_90) * 6.0;
+        assert_float_eq!(turns.to_value(), 1.5, abs <= 1e-6);
+        let turns: Turns<AngleUnbounded32> = turns / 3.0;
+        assert_float_eq!(turns.to_value(), 0.5, abs <= 1e-6);
+
+        let radians = Radians(Angle64::from_radians(3.0)) + Radians(Angle64::from_radians(1.0));
+        assert_float_eq!(
+            radians.to_value(),
+            4.0 - core::f64::consts::TAU,
+            abs <= 1e-12
+        );
+    }
+
+    #[test]
+    fn units_sum() {
+        let angles = [
+            Angle32::from_degrees(30.0),
+            Angle32::from_degrees(100.0),
+            Angle32::from_degrees(-45.0),
+            Angle32::from_degrees(170.0),
+        ];
+
+        let sum: Degrees<Angle32> = angles.iter().copied().map(Degrees).sum();
+        assert_eq!(sum.0, angles.iter().copied().sum::<Angle32>());
+        assert_float_eq!(sum.to_value(), -105.0, abs <= 1e-4);
+
+        let sum: Turns<AngleUnbounded32> = [1.5, 2.0, -0.25]
+            .into_iter()
+            .map(|x| Turns(AngleUnbounded32::from_turns(x)))
+            .sum();
+        assert_float_eq!(sum.to_value(), 3.25, abs <= 1e-5);
+
+        let empty: Radians<Angle64> = core::iter::empty().sum();
+        assert_eq!(empty.0, Angle64::ZERO);
+    }
+
+    #[test]
+    fn arcminutes_and_arcseconds_roundtrip() {
+        let angle = Angle32::from_degrees(1.5);
+        assert_float_eq!(Arcminutes(angle).to_value(), 90.0, abs <= 1e-4);
+        assert_float_eq!(Arcseconds(angle).to_value(), 5400.0, abs <= 1e-2);
+
+        let angle = Arcminutes::<Angle32>::from_value(90.0).0;
+        assert_float_eq!(angle.to_degrees(), 1.5, abs <= 1e-5);
+        let angle = Arcseconds::<Angle32>::from_value(5400.0).0;
+        assert_float_eq!(angle.to_degrees(), 1.5, abs <= 1e-5);
+
+        let angle = Arcminutes::<AngleUnbounded32>::from_value(43_200.0).0;
+        assert_float_eq!(angle.to_turns(), 2.0, abs <= 1e-5);
+        let angle = Arcseconds::<AngleUnbounded32>::from_value(-1_296_000.0).0;
+        assert_float_eq!(angle.to_turns(), -1.0, abs <= 1e-5);
+    }
+
+    #[test]
+    fn dms_decomposition() {
+        let angle = Angle64::from_degrees(12.0 + 34.0 / 60.0 + 56.7 / 3600.0);
+        let dms = Dms::from_angle(angle);
+
+        assert_eq!(dms.degrees, 12);
+        assert_eq!(dms.minutes, 34);
+        assert_float_eq!(dms.seconds, 56.7, abs <= 1e-6);
+        assert!(!dms.negative);
+
+        assert_float_eq!(
+            dms.to_angle().to_radians(),
+            angle.to_radians(),
+            abs <= 1e-12
+        );
+    }
+
+    #[test]
+    fn dms_negative() {
+        let angle = Angle64::from_degrees(-(45.0 + 6.0 / 60.0 + 30.0 / 3600.0));
+        let dms = Dms::from_angle(angle);
+
+        assert_eq!(dms.degrees, 45);
+        assert_eq!(dms.minutes, 6);
+        assert_float_eq!(dms.seconds, 30.0, abs <= 1e-6);
+        assert!(dms.negative);
+
+        assert_float_eq!(
+            dms.to_angle().to_radians(),
+            angle.to_radians(),
+            abs <= 1e-12
+        );
+
+        let dms = Dms::from_angle(Angle64::from_degrees(-0.5));
+        assert_eq!(dms.degrees, 0);
+        assert_eq!(dms.minutes, 30);
+        assert!(dms.negative);
+    }
+
+    #[test]
+    fn dms_rounding_carries() {
+        let angle = Angle64::from_degrees(12.0 + 34.0 / 60.0 + 59.9999 / 3600.0);
+        let dms = Dms::from_angle(angle);
+        assert_eq!(dms.minutes, 34);
+
+        let dms = dms.round_seconds(2);
+        assert_eq!(dms.degrees, 12);
+        assert_eq!(dms.minutes, 35);
+        assert_float_eq!(dms.seconds, 0.0, abs <= 1e-9);
+
+        let angle = Angle64::from_degrees(12.0 + 59.0 / 60.0 + 59.9999 / 3600.0);
+        let dms = Dms::from_angle(angle).round_seconds(1);
+        assert_eq!(dms.degrees, 13);
+        assert_eq!(dms.minutes, 0);
+        assert_float_eq!(dms.seconds, 0.0, abs <= 1e-9);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dms_display() {
+        let angle = Angle64::from_degrees(12.0 + 34.0 / 60.0 + 56.7 / 3600.0);
+        assert_eq!(format!("{:.1}", Dms::from_angle(angle)), "12°34'56.7\"");
+
+        let angle = Angle64::from_degrees(-(45.0 + 6.0 / 60.0 + 30.0 / 3600.0));
+        assert_eq!(format!("{:.0}", Dms::from_angle(angle)), "-45°6'30\"");
+
+        // The seconds are rounded before being displayed, carrying into the minutes.
+        let angle = Angle64::from_degrees(12.0 + 34.0 / 60.0 + 59.9999 / 3600.0);
+        assert_eq!(format!("{:.2}", Dms::from_angle(angle)), "12°35'0.00\"");
+
+        assert_eq!(format!("{:.0}", Dms::from(Angle32::DEG_90)), "90°0'0\"");
+
+        // Without precision, the rounding errors are hidden.
+        let angle = Angle64::from_degrees(12.0 + 34.0 / 60.0 + 56.7 / 3600.0);
+        assert_eq!(format!("{}", Dms::from_angle(angle)), "12°34'56.7\"");
+        let angle = Angle64::from_degrees(-(45.0 + 6.0 / 60.0 + 30.0 / 3600.0));
+        assert_eq!(format!("{}", Dms::from_angle(angle)), "-45°6'30\"");
+        let angle = Angle64::from_degrees(12.0 + 34.0 / 60.0 + 59.999_999_999_9 / 3600.0);
+        assert_eq!(format!("{}", Dms::from_angle(angle)), "12°35'0\"");
+        assert_eq!(format!("{}", Dms::from(Angle32::DEG_90)), "90°0'0\"");
+        assert_eq!(format!("{}", Dms::from(Angle32::DEG_30)), "30°0'0\"");
+        assert_eq!(format!("{:+}", Dms::from(Angle64::DEG_45)), "+45°0'0\"");
+    }
+
+    #[test]
+    fn mils_roundtrip() {
+        assert_float_eq!(Mils(Angle32::QUARTER).to_value(), 1600.0, abs <= 1e-3);
+        assert_float_eq!(Mils(-Angle32::QUARTER).to_value(), -1600.0, abs <= 1e-3);
+        assert_float_eq!(Mils(Angle32::HALF).to_value(), 3200.0, abs <= 1e-3);
+
+        let angle = Mils::<Angle32>::from_value(800.0).0;
+        assert_float_eq!(angle.to_degrees(), 45.0, abs <= 1e-4);
+        assert_float_eq!(Mils(angle).to_value(), 800.0, abs <= 1e-3);
+
+        let angle = Mils::<AngleUnbounded32>::from_value(9600.0).0;
+        assert_float_eq!(angle.to_turns(), 1.5, abs <= 1e-5);
+    }
+
+    #[test]
+    fn hours_roundtrip() {
+        assert_float_eq!(
+            Hours::<Angle32>::from_value(6.0).0.to_radians(),
+            Angle32::DEG_90.to_radians(),
+            abs <= 1e-6
+        );
+        assert_float_eq!(Hours(Angle32::DEG_90).to_value(), 6.0, abs <= 1e-6);
+        assert_float_eq!(Hours(Angle32::HALF).to_value(), 12.0, abs <= 1e-6);
+
+        let angle = Hours::<Angle64>::from_value(-4.5).0;
+        assert_float_eq!(angle.to_degrees(), -67.5, abs <= 1e-9);
+        assert_float_eq!(Hours(angle).to_value(), -4.5, abs <= 1e-9);
+
+        let angle = Hours::<AngleUnbounded32>::from_value(36.0).0;
+        assert_float_eq!(angle.to_turns(), 1.5, abs <= 1e-5);
+
+        let angle = Hours::<Angle32>::from_value(12.5).0;
+        assert_float_eq!(Hours(angle).to_value(), -11.5, abs <= 1e-5);
+    }
+
+    #[test]
+    fn gon_from_str() {
+        assert_float_eq!(
+            Gon(Angle64::DEG_60).to_value(),
+            Gradians(Angle64::DEG_60).to_value(),
+            ulps <= 0
+        );
+
+        let angle: Gon<Angle32> = "100 gon".parse().unwrap();
+        assert_float_eq!(angle.0.to_degrees(), 90.0, abs <= 1e-4);
+        let angle: Gon<AngleUnbounded32> = "-450gon".parse().unwrap();
+        assert_float_eq!(angle.0.to_turns(), -1.125, abs <= 1e-6);
+        let angle: Gon<Angle32> = "100".parse().unwrap();
+        assert_float_eq!(angle.0.to_degrees(), 90.0, abs <= 1e-4);
+
+        assert!("100g".parse::<Gon<Angle32>>().is_err());
+        assert!("100 gon".parse::<Gradians<Angle32>>().is_err());
+        assert!("100 grad".parse::<Gon<Angle32>>().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn gon_display() {
+        assert_eq!(Gon(Angle32::DEG_90).to_string(), "100 gon");
+        assert_eq!(format!("{:.1}", Gon(-Angle64::DEG_45)), "-50.0 gon");
+
+        let angle: Gon<Angle64> = "50 gon".parse().unwrap();
+        let angle: Gon<Angle64> = angle.to_string().parse().unwrap();
+        assert_float_eq!(angle.to_value(), 50.0, abs <= 1e-12);
+    }
+
+    #[test]
+    fn units_from_str() {
+        let angle: Degrees<Angle32> = "90°".parse().unwrap();
+        assert_float_eq!(angle.0.to_degrees(), 90.0, abs <= 1e-4);
+        let angle: Degrees<Angle32> = " 90 ".parse().unwrap();
+        assert_float_eq!(angle.0.to_degrees(), 90.0, abs <= 1e-4);
+        let angle: Radians<Angle32> = "1.5 rad".parse().unwrap();
+        assert_float_eq!(angle.0.to_radians(), 1.5, abs <= 1e-6);
+        let angle: Radians<Angle32> = "1.5rad".parse().unwrap();
+        assert_float_eq!(angle.0.to_radians(), 1.5, abs <= 1e-6);
+        let angle: Turns<AngleUnbounded32> = "1.25 tr".parse().unwrap();
+        assert_float_eq!(angle.0.to_turns(), 1.25, abs <= 1e-6);
+        let angle: Gradians<Angle32> = "100g".parse().unwrap();
+        assert_float_eq!(angle.0.to_degrees(), 90.0, abs <= 1e-4);
+
+        assert!("90 rad".parse::<Degrees<Angle32>>().is_err());
+        assert!("90°".parse::<Radians<Angle32>>().is_err());
+        assert!("0.25 g".parse::<Turns<Angle32>>().is_err());
+        assert!("100 tr".parse::<Gradians<Angle32>>().is_err());
+        assert!("°".parse::<Degrees<Angle32>>().is_err());
+        assert!("".parse::<Degrees<Angle32>>().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn units_from_str_to_string_is_stable() {
+        macro_rules! check {
+            ($Unit:ident<$Angle:ty>: $($s:expr),*) => {
+                $(
+                    let s = $s.parse::<$Unit<$Angle>>().unwrap().to_string();
+                    assert_eq!(s.parse::<$Unit<$Angle>>().unwrap().to_string(), s);
+                )*
+            };
+        }
+
+        check!(Degrees<Angle32>: "90°", "-45°", "180°", "30");
+        check!(Degrees<Angle64>: "90°", "-45.5°", "12.345");
+        check!(Radians<Angle32>: "1.5 rad", "-3 rad", "0.1");
+        check!(Turns<AngleUnbounded32>: "1.25 tr", "-0.3 tr");
+
+        // The value is stored in radians, so other units may drift in the last digits.
+        let angle: Gradians<Angle64> = "100g".parse().unwrap();
+        let angle: Gradians<Angle64> = angle.to_string().parse().unwrap();
+        assert_float_eq!(angle.to_value(), 100.0, abs <= 1e-12);
+
+        assert_eq!("90".parse::<Degrees<Angle32>>().unwrap().to_string(), "90°");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn units_display_precision() {
+        assert_eq!(format!("{:.2}", Degrees(Angle32::DEG_90)), "90.00°");
+        assert_eq!(format!("{:.0}", Degrees(Angle64::DEG_45)), "45°");
+        assert_eq!(format!("{:.1}", Degrees(-Angle32::DEG_30)), "-30.0°");
+        assert_eq!(format!("{:.3}", Radians(Angle32::DEG_90)), "1.571 rad");
+        assert_eq!(
+            format!("{:.2}", Turns(AngleUnbounded32::from_turns(1.5))),
+            "1.50 tr"
+        );
+        assert_eq!(format!("{:.1}", Gradians(Angle64::DEG_90)), "100.0g");
+        assert_eq!(format!("{}", Degrees(Angle32::DEG_90)), "90°");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn units_display_sign_plus() {
+        assert_eq!(format!("{:+}", Degrees(Angle32::DEG_90)), "+90°");
+        assert_eq!(format!("{:+}", Degrees(-Angle32::DEG_90)), "-90°");
+        assert_eq!(format!("{:+.1}", Degrees(Angle64::DEG_45)), "+45.0°");
+        assert_eq!(format!("{:+.1}", Degrees(-Angle64::DEG_45)), "-45.0°");
+        assert_eq!(
+            format!("{:+}", Turns(AngleUnbounded32::from_turns(1.5))),
+            "+1.5 tr"
+        );
+        assert_eq!(format!("{:+}", Degrees(Angle32::ZERO)), "+0°");
+
+        assert_eq!(format!("{:+.0}", Dms::from(Angle32::DEG_90)), "+90°0'0\"");
+        assert_eq!(format!("{:+.0}", Dms::from(-Angle32::DEG_90)), "-90°0'0\"");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn units_display_as() {
+        use crate::units::Unit;
+
+        let angle = Angle32::DEG_90;
+        assert_eq!(angle.display_as(Unit::Radians).to_string(), "1.5707964 rad");
+        assert_eq!(angle.display_as(Unit::Degrees).to_string(), "90°");
+        assert_eq!(angle.display_as(Unit::Turns).to_string(), "0.25 tr");
+        assert_eq!(angle.display_as(Unit::Gradians).to_string(), "100g");
+
+        // The formatter flags are forwarded.
+        let angle = Angle64::DEG_45;
+        assert_eq!(
+            format!("{:.2}", angle.display_as(Unit::Radians)),
+            "0.79 rad"
+        );
+        assert_eq!(format!("{:+.1}", angle.display_as(Unit::Degrees)), "+45.0°");
+        assert_eq!(
+            format!("{:.3}", (-angle).display_as(Unit::Turns)),
+            "-0.125 tr"
+        );
+        assert_eq!(format!("{:.0}", angle.display_as(Unit::Gradians)), "50g");
+    }
+
+    #[test]
+    fn custom_unit_roundtrip() {
+        let mil = CustomUnit::new(" mil", 6400.0_f32);
+
+        assert_float_eq!(mil.to_value(Angle32::QUARTER), 1600.0, abs <= 1e-3);
+        assert_float_eq!(mil.to_value(-Angle32::QUARTER), -1600.0, abs <= 1e-3);
+        assert_float_eq!(
+            mil.to_value(Angle32::DEG_60),
+            Mils(Angle32::DEG_60).to_value(),
+            abs <= 1e-3
+        );
+
+        for x in [0.0, 800.0, -1234.5, 3199.0] {
+            assert_float_eq!(mil.to_value(mil.from_value(x)), x, abs <= 1e-2);
+        }
+        // Wrapped into the main range.
+        assert_float_eq!(mil.to_value(mil.from_value(7200.0)), 800.0, abs <= 1e-2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn custom_unit_display() {
+        let mil = CustomUnit::new(" mil", 6400.0_f32);
+
+        assert_eq!(mil.display(Angle32::QUARTER).to_string(), "1600 mil");
+        assert_eq!(
+            format!("{:+.1}", mil.display(Angle32::QUARTER)),
+            "+1600.0 mil"
+        );
+        assert_eq!(
+            format!("{:.2}", mil.display(Angle32::DEG_60)),
+            "1066.67 mil"
+        );
+    }
+}